@@ -45,9 +45,9 @@ export class MissionRegistryContractService {
 
     async sealProof(data: {
         validatorKey: string;
-        reaperAddress: string;
         missionId: string;
         proofCid: string;
+        note?: string;
     }): Promise<ApiResponse<any>> {
         return apiClient.request('/contracts/mission-registry/seal-proof', {
             method: 'POST',
@@ -26,9 +26,9 @@ const setTokenContract = AsyncHandler(async (req: Request, res: Response) => {
 });
 
 const registerMission = AsyncHandler(async (req: Request, res: Response) => {
-  const { captainKey, missionId, title, dangerLevel, deadline } = req.body;
+  const { captainKey, missionId, title, dangerLevel, deadline, division } = req.body;
   if (!captainKey || !missionId || !title || dangerLevel === undefined) throw new ApiError(400, 'Missing required fields');
-  const result = await missionRegistryService.registerMission(captainKey, missionId, title, dangerLevel, deadline);
+  const result = await missionRegistryService.registerMission(captainKey, missionId, title, dangerLevel, deadline, division);
   return res.status(200).json(new ApiResponse(200, result, 'Mission registered'));
 });
 
@@ -40,12 +40,19 @@ const advanceStatus = AsyncHandler(async (req: Request, res: Response) => {
 });
 
 const sealProof = AsyncHandler(async (req: Request, res: Response) => {
-  const { validatorKey, reaperAddress, missionId, proofCid } = req.body;
-  if (!validatorKey || !reaperAddress || !missionId || !proofCid) throw new ApiError(400, 'Missing required fields');
-  const result = await missionRegistryService.sealProof(validatorKey, reaperAddress, missionId, proofCid);
+  const { validatorKey, missionId, proofCid, note } = req.body;
+  if (!validatorKey || !missionId || !proofCid) throw new ApiError(400, 'Missing required fields');
+  const result = await missionRegistryService.sealProof(validatorKey, missionId, proofCid, note);
   return res.status(200).json(new ApiResponse(200, result, 'Proof sealed'));
 });
 
+const rejectReview = AsyncHandler(async (req: Request, res: Response) => {
+  const { validatorKey, missionId, reasonCid } = req.body;
+  if (!validatorKey || !missionId || !reasonCid) throw new ApiError(400, 'Missing required fields');
+  const result = await missionRegistryService.rejectReview(validatorKey, missionId, reasonCid);
+  return res.status(200).json(new ApiResponse(200, result, 'Review rejected'));
+});
+
 const failMission = AsyncHandler(async (req: Request, res: Response) => {
   const { callerKey, missionId } = req.body;
   if (!callerKey || !missionId) throw new ApiError(400, 'Caller key and mission ID are required');
@@ -67,6 +74,20 @@ const getProof = AsyncHandler(async (req: Request, res: Response) => {
   return res.status(200).json(new ApiResponse(200, proof, 'Proof retrieved'));
 });
 
+const getProofs = AsyncHandler(async (req: Request, res: Response) => {
+  const { missionId } = req.params;
+  if (!missionId) throw new ApiError(400, 'Mission ID is required');
+  const proofs = await missionRegistryService.getProofs(missionId);
+  return res.status(200).json(new ApiResponse(200, proofs, 'Proofs retrieved'));
+});
+
+const getRejections = AsyncHandler(async (req: Request, res: Response) => {
+  const { missionId } = req.params;
+  if (!missionId) throw new ApiError(400, 'Mission ID is required');
+  const rejections = await missionRegistryService.getRejections(missionId);
+  return res.status(200).json(new ApiResponse(200, rejections, 'Rejections retrieved'));
+});
+
 const getCounter = AsyncHandler(async (req: Request, res: Response) => {
   const counter = await missionRegistryService.getCounter();
   return res.status(200).json(new ApiResponse(200, counter, 'Counter retrieved'));
@@ -93,9 +114,12 @@ export {
   registerMission,
   advanceStatus,
   sealProof,
+  rejectReview,
   failMission,
   getMission,
   getProof,
+  getProofs,
+  getRejections,
   getCounter,
   getMissionsByCaptain,
   getValidators
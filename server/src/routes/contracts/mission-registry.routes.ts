@@ -6,9 +6,12 @@ import {
   registerMission,
   advanceStatus,
   sealProof,
+  rejectReview,
   failMission,
   getMission,
   getProof,
+  getProofs,
+  getRejections,
   getCounter,
   getMissionsByCaptain,
   getValidators
@@ -25,11 +28,14 @@ router.post('/set-token-contract', setTokenContract);
 router.post('/register', registerMission);
 router.post('/advance-status', advanceStatus);
 router.post('/seal-proof', sealProof);
+router.post('/reject-review', rejectReview);
 router.post('/fail', failMission);
 
 // Query endpoints
 router.get('/mission/:missionId', getMission);
 router.get('/mission/:missionId/proof', getProof);
+router.get('/mission/:missionId/proofs', getProofs);
+router.get('/mission/:missionId/rejections', getRejections);
 router.get('/counter', getCounter);
 router.get('/captain/:captainAddress', getMissionsByCaptain);
 router.get('/mission/:missionId/validators', getValidators);
@@ -13,6 +13,9 @@ import { server, STACK_ADMIN_SECRET } from './smartContract.handler.stellar.js';
 
 // This will be set after deployment
 const ESCROW_CONTRACT_ID = process.env.ESCROW_CONTRACT_ID || '';
+// SEP-41 asset the escrow takes custody of (native XLM SAC by default);
+// the escrow admin must accept it with set_token_accepted
+const ESCROW_TOKEN_CONTRACT_ID = process.env.ESCROW_TOKEN_CONTRACT_ID || '';
 
 export class EscrowService {
     private server = server;
@@ -31,6 +34,7 @@ export class EscrowService {
         deadline: number
     ) {
         if (!ESCROW_CONTRACT_ID) throw new Error('ESCROW_CONTRACT_ID not configured');
+        if (!ESCROW_TOKEN_CONTRACT_ID) throw new Error('ESCROW_TOKEN_CONTRACT_ID not configured');
 
         const contract = new Contract(ESCROW_CONTRACT_ID);
         const sourceAccount = await this.server.getAccount(donorPublicKey);
@@ -43,10 +47,24 @@ export class EscrowService {
                 'create_escrow',
                 new Address(donorPublicKey).toScVal(),
                 new Address(ngoPublicKey).toScVal(),
-                nativeToScVal(BigInt(Math.round(totalAmount)), { type: 'i128' }),
-                nativeToScVal(BigInt(Math.round(lockedAmount)), { type: 'i128' }),
+                new Address(ESCROW_TOKEN_CONTRACT_ID).toScVal(),
                 nativeToScVal(taskId, { type: 'string' }),
-                nativeToScVal(BigInt(deadline), { type: 'u64' })
+                // EscrowFunding struct
+                nativeToScVal(
+                    {
+                        deadline: BigInt(deadline),
+                        locked_amount: BigInt(Math.round(lockedAmount)),
+                        total_amount: BigInt(Math.round(totalAmount)),
+                    },
+                    {
+                        type: {
+                            deadline: ['symbol', 'u64'],
+                            locked_amount: ['symbol', 'i128'],
+                            total_amount: ['symbol', 'i128'],
+                        },
+                    }
+                ),
+                xdr.ScVal.scvVoid() // policy: None → current global policy
             ))
             .setTimeout(180)
             .build();
@@ -380,7 +398,7 @@ export class EscrowService {
         if (simAny.result?.retval) {
             return scValToNative(simAny.result.retval);
         }
-        return { locked: 0, released: 0, refunded: 0 };
+        return { locked: 0, released: 0, refunded: 0, fees: 0 };
     }
 }
 
@@ -113,7 +113,8 @@ export class MissionRegistryService {
         missionId: string,
         title: string,
         dangerLevel: number,
-        deadline?: number
+        deadline?: number,
+        division = 0
     ) {
         if (!MISSION_REGISTRY_CONTRACT_ID) throw new Error('MISSION_REGISTRY_CONTRACT_ID not configured');
 
@@ -132,7 +133,8 @@ export class MissionRegistryService {
                 nativeToScVal(missionId, { type: 'string' }),
                 nativeToScVal(title, { type: 'string' }),
                 nativeToScVal(dangerLevel, { type: 'u32' }),
-                nativeToScVal(BigInt(deadlineValue), { type: 'u64' })
+                nativeToScVal(BigInt(deadlineValue), { type: 'u64' }),
+                nativeToScVal(division, { type: 'u32' })
             ))
             .setTimeout(30)
             .build();
@@ -180,9 +182,9 @@ export class MissionRegistryService {
      */
     async sealProof(
         validatorKey: string,
-        reaperAddress: string,
         missionId: string,
-        proofCid: string
+        proofCid: string,
+        note = ''
     ) {
         if (!MISSION_REGISTRY_CONTRACT_ID) throw new Error('MISSION_REGISTRY_CONTRACT_ID not configured');
 
@@ -197,9 +199,9 @@ export class MissionRegistryService {
             .addOperation(contract.call(
                 'seal_proof',
                 new Address(validatorKeypair.publicKey()).toScVal(),
-                new Address(reaperAddress).toScVal(),
                 nativeToScVal(missionId, { type: 'string' }),
-                nativeToScVal(proofCid, { type: 'string' })
+                nativeToScVal(proofCid, { type: 'string' }),
+                nativeToScVal(note, { type: 'string' })
             ))
             .setTimeout(30)
             .build();
@@ -302,6 +304,101 @@ export class MissionRegistryService {
         return null;
     }
 
+    /**
+     * Reject a mission in Review back to InProgress with a reason CID.
+     */
+    async rejectReview(
+        validatorKey: string,
+        missionId: string,
+        reasonCid: string
+    ) {
+        if (!MISSION_REGISTRY_CONTRACT_ID) throw new Error('MISSION_REGISTRY_CONTRACT_ID not configured');
+
+        const contract = new Contract(MISSION_REGISTRY_CONTRACT_ID);
+        const validatorKeypair = Keypair.fromSecret(validatorKey);
+        const sourceAccount = await this.server.getAccount(validatorKeypair.publicKey());
+
+        const tx = new TransactionBuilder(sourceAccount, {
+            fee: "100",
+            networkPassphrase: Networks.TESTNET
+        })
+            .addOperation(contract.call(
+                'reject_review',
+                new Address(validatorKeypair.publicKey()).toScVal(),
+                nativeToScVal(missionId, { type: 'string' }),
+                nativeToScVal(reasonCid, { type: 'string' })
+            ))
+            .setTimeout(30)
+            .build();
+
+        const preparedTx = await this.server.prepareTransaction(tx);
+        preparedTx.sign(validatorKeypair);
+        const result = await this.server.sendTransaction(preparedTx);
+
+        logger.info(`[MissionRegistry] Review rejected for mission: ${missionId} - ${result.hash}`);
+        return result;
+    }
+
+    /**
+     * Get every rejected Review for a mission, oldest first.
+     */
+    async getRejections(missionId: string) {
+        if (!MISSION_REGISTRY_CONTRACT_ID) throw new Error('MISSION_REGISTRY_CONTRACT_ID not configured');
+
+        const contract = new Contract(MISSION_REGISTRY_CONTRACT_ID);
+        const sourceAccount = await this.server.getAccount(this.adminKeypair.publicKey());
+
+        const tx = new TransactionBuilder(sourceAccount, {
+            fee: "100",
+            networkPassphrase: Networks.TESTNET
+        })
+            .addOperation(contract.call(
+                'get_rejections',
+                nativeToScVal(missionId, { type: 'string' })
+            ))
+            .setTimeout(30)
+            .build();
+
+        const preparedTx = await this.server.prepareTransaction(tx);
+        const simulation = await this.server.simulateTransaction(preparedTx);
+
+        const simAny = simulation as any;
+        if (simAny.result?.retval) {
+            return scValToNative(simAny.result.retval);
+        }
+        return [];
+    }
+
+    /**
+     * Get every validator's proof for a mission, oldest first.
+     */
+    async getProofs(missionId: string) {
+        if (!MISSION_REGISTRY_CONTRACT_ID) throw new Error('MISSION_REGISTRY_CONTRACT_ID not configured');
+
+        const contract = new Contract(MISSION_REGISTRY_CONTRACT_ID);
+        const sourceAccount = await this.server.getAccount(this.adminKeypair.publicKey());
+
+        const tx = new TransactionBuilder(sourceAccount, {
+            fee: "100",
+            networkPassphrase: Networks.TESTNET
+        })
+            .addOperation(contract.call(
+                'get_proofs',
+                nativeToScVal(missionId, { type: 'string' })
+            ))
+            .setTimeout(30)
+            .build();
+
+        const preparedTx = await this.server.prepareTransaction(tx);
+        const simulation = await this.server.simulateTransaction(preparedTx);
+
+        const simAny = simulation as any;
+        if (simAny.result?.retval) {
+            return scValToNative(simAny.result.retval);
+        }
+        return [];
+    }
+
     /**
      * Get mission counter statistics.
      */
@@ -164,7 +164,8 @@ export async function registerMission(
   missionId: string,
   title: string,
   dangerLevel: number,
-  deadline?: number
+  deadline?: number,
+  division = 0
 ) {
   try {
     const contractId = process.env.MISSION_REGISTRY_CONTRACT_ID;
@@ -192,7 +193,8 @@ export async function registerMission(
           StellarSdk.nativeToScVal(missionId, { type: 'string' }),
           StellarSdk.nativeToScVal(title, { type: 'string' }),
           StellarSdk.nativeToScVal(dangerLevel, { type: 'u32' }),
-          StellarSdk.nativeToScVal(BigInt(deadlineValue), { type: 'u64' })
+          StellarSdk.nativeToScVal(BigInt(deadlineValue), { type: 'u64' }),
+          StellarSdk.nativeToScVal(division, { type: 'u32' })
         )
       )
       .build();
@@ -208,7 +210,7 @@ export async function registerMission(
   }
 }
 
-export async function sealMissionProof(validatorKey: string, reaperAddress: string, missionId: string, proofCid: string) {
+export async function sealMissionProof(validatorKey: string, missionId: string, proofCid: string, note = '') {
   try {
     const contractId = process.env.MISSION_REGISTRY_CONTRACT_ID;
     if (!contractId) {
@@ -229,9 +231,9 @@ export async function sealMissionProof(validatorKey: string, reaperAddress: stri
         contract.call(
           'seal_proof',
           StellarSdk.nativeToScVal(accountId, { type: 'address' }),
-          StellarSdk.nativeToScVal(reaperAddress, { type: 'address' }),
           StellarSdk.nativeToScVal(missionId, { type: 'string' }),
-          StellarSdk.nativeToScVal(proofCid, { type: 'string' })
+          StellarSdk.nativeToScVal(proofCid, { type: 'string' }),
+          StellarSdk.nativeToScVal(note, { type: 'string' })
         )
       )
       .build();
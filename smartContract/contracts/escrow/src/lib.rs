@@ -1,7 +1,7 @@
 #![no_std]
 use soroban_sdk::{
-    contract, contractimpl, contracttype, symbol_short,
-    Env, Address, String, Vec,
+    contract, contracterror, contractimpl, contracttype, symbol_short, token, xdr::ToXdr,
+    Env, Address, Bytes, BytesN, IntoVal, String, Symbol, Vec,
 };
 
 mod test;
@@ -10,11 +10,109 @@ mod test;
 //  ESCROW CONTRACT — LOCK, DO WORK, GET PAID
 //  Architecture: Trustless escrow for charitable donations.
 //  Flow: Donor donates → 50% immediate to NGO, 50% locked in escrow
+//        (held by this contract via the escrow's SEP-41 token)
+//  Tokens: Only admin-accepted tokens can be escrowed; they share one
+//          accounting unit, so platform totals, NGO reputation and the
+//          reputation gate can add amounts across escrows
 //        → NGO completes task → Community verifies → Release or Refund
 //  Dispute: Community votes; if scam (>60%) → 30-day lock → Refund
-//  Rewards: Accurate scam detectors earn badges + tokens
+//  Policy: Threshold, lock, quorum and voter reward are admin-tunable; each
+//          escrow captures the policy (or a stricter donor override) at creation
+//  Appeal: During the lock the NGO may appeal; the arbitrator panel set when
+//          it was filed decides between Released and Refunded, or the
+//          refund goes ahead if the panel has not decided in time
+//  Proofs: Every submission is versioned; a resubmitted proof resets the
+//          tally or archives the old votes as a closed round. Resubmission is
+//          limited to an open window and an undecided tally, a few times over
+//  Voting window: Votes open only once proof is submitted and stay open for
+//                 the policy's window; the tally is final when it closes
+//  Reputation: Each NGO's releases, disputes and refunds feed a trust score;
+//              large escrows can require a minimum score
+//  Deadline: No proof by the deadline, or a final tally that met neither
+//            quorum → anyone can mark it Expired → Refund
+//  Settlement is permissionless: thresholds and timers gate it, not the admin
+//  Early exits: the donor may release to the NGO at any time; donor and
+//               NGO may jointly cancel an escrow nobody has voted on yet
+//  Milestones: Escrow split into ordered slices, each proven, voted on
+//              and released separately; a dispute freezes the remainder
+//  Pooled: Many donors top up one escrow until its target or deadline;
+//          one vote round, refunds split pro-rata by contribution
+//  Voting: Direct, or commit–reveal (hash of voter, escrow, proof round,
+//          choice and salt, then reveal)
+//  Delegation: Voters may delegate globally or per NGO; once the delegate
+//              accepts (up to a per-delegate cap), the delegate's vote carries
+//              their weight unless they vote directly, bonded by the delegate
+//  Weighting: Optionally votes are weighted by the voter's accuracy record
+//             and/or Soul Badge tier; decisions use the weighted shares
+//  Voters: Optionally restricted to active Soul Reapers (registry lookup)
+//          holding a minimum number of Soul Badges
+//  Receipts: Donors get a non-transferable receipt when their funds lock
+//            and another recording the outcome when the escrow settles
+//  Fees: An admin-set basis-point fee on releases (optionally refunds) goes
+//        to the division treasury
+//  Rewards: Accurate voters earn Reiatsu (minted or paid from a reward pool)
+//  Bonds: Optionally each vote locks a Reiatsu bond; wrong voters are
+//         slashed and the slashed bonds are shared among correct voters
 // ═══════════════════════════════════════════════════════════════════
 
+// ── Errors ───────────────────────────────────────────────────────
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+    EscrowNotFound = 3,
+    EscrowAlreadyExists = 4,
+    InvalidAmount = 5,
+    InvalidConfig = 6,
+    InvalidMilestones = 7,
+    WrongEscrowKind = 8,
+    NotLocked = 9,
+    NotDisputed = 10,
+    NotRefundable = 11,
+    NotCurrentMilestone = 12,
+    NoPendingMilestone = 13,
+    CannotVoteOwnEscrow = 14,
+    AlreadyVoted = 15,
+    WrongVotingMode = 16,
+    WrongVotingPhase = 17,
+    VotingStarted = 18,
+    CommitmentNotFound = 19,
+    AlreadyRevealed = 20,
+    CommitmentMismatch = 21,
+    NotEnoughVotes = 22,
+    NotEnoughRealVotes = 23,
+    NotEnoughScamVotes = 24,
+    LockNotExpired = 25,
+    DeadlineNotPassed = 26,
+    ProofSubmitted = 27,
+    AppealPending = 28,
+    NotAppealed = 29,
+    AppealWindowClosed = 30,
+    EvidenceRequired = 31,
+    NoArbitrators = 32,
+    AlreadyAppealed = 33,
+    NotArbitrator = 34,
+    RewardsNotConfigured = 35,
+    NotRegisteredReaper = 36,
+    ReaperNotActive = 37,
+    NotEnoughBadges = 38,
+    ExceedsTarget = 39,
+    FundingClosed = 40,
+    PolicyTooLax = 41,
+    ReputationTooLow = 42,
+    InvalidDelegate = 43,
+    NotDelegated = 44,
+    FeesNotConfigured = 45,
+    ProofRequired = 46,
+    DonorIsNgo = 47,
+    TooManyDelegators = 48,
+    TallyDecided = 49,
+    TooManyProofRounds = 50,
+}
+
 // ── Data Types ───────────────────────────────────────────────────
 
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
@@ -24,25 +122,143 @@ pub enum EscrowStatus {
     Released,    // Task verified, funds released to NGO
     Disputed,    // Community flagged as scam, 30-day lock started
     Refunded,    // Dispute confirmed, funds returned to donor
+    Expired,     // Deadline passed without proof, refundable to donor
+    Appealed,    // NGO appealed the dispute, awaiting arbitration
+    DonorReleased, // Donor released the funds to the NGO without a vote
+    Cancelled,   // Donor and NGO cancelled before any vote, donor refunded
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[contracttype]
+pub enum EscrowKind {
+    Standard,    // Single lock, single proof, single vote round
+    Milestone,   // Ordered milestones, each with its own proof and vote round
+    Pooled,      // Many contributors, single proof and vote round
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[contracttype]
+pub enum MilestoneStatus {
+    Pending,     // Awaiting proof / votes
+    Released,    // Slice paid to NGO
+    Frozen,      // Held by a dispute on this or an earlier milestone
+    Refunded,    // Slice returned to donor
+}
+
+/// What happens to votes already cast when the NGO resubmits proof.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[contracttype]
+pub enum ResubmitMode {
+    ResetTally,  // Earlier votes are discarded
+    NewRound,    // Earlier votes are archived as a closed round (see `get_round_votes`)
+}
+
+/// Decision rules an escrow is settled under.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub struct EscrowPolicy {
+    pub dispute_threshold: u32,    // % of votes needed to release or dispute
+    pub lock_duration: u64,        // dispute lock before refund, in ledger seconds
+    pub min_votes: u32,            // votes needed before any decision
+    pub voter_reward: i128,        // Reiatsu per correct vote (platform funded)
+    pub vote_window: u64,          // direct voting period after proof (0 = open until settled)
+}
+
+/// Amounts and deadline supplied by the donor when creating a standard or pooled escrow.
+#[derive(Clone, Debug)]
+#[contracttype]
+pub struct EscrowFunding {
+    pub total_amount: i128,        // full donation, or the funding target of a pool
+    pub locked_amount: i128,       // locked now, or the creator's opening contribution to a pool
+    pub deadline: u64,
+}
+
+/// Milestone definition supplied by the donor at creation.
+#[derive(Clone, Debug)]
+#[contracttype]
+pub struct MilestoneSpec {
+    pub amount: i128,
+    pub deadline: u64,
+}
+
+#[derive(Clone, Debug)]
+#[contracttype]
+pub struct Milestone {
+    pub amount: i128,
+    pub deadline: u64,
+    pub status: MilestoneStatus,
+    pub proof_cid: String,
+    pub real_votes: u32,
+    pub scam_votes: u32,
+    pub real_weight: u32,
+    pub scam_weight: u32,
+    pub vote_start: u64,           // when its proof opened voting (0 = no proof yet)
+    pub released_at: u64,
 }
 
 #[derive(Clone, Debug)]
 #[contracttype]
 pub struct EscrowData {
-    pub donor: Address,
+    pub donor: Address,            // creator for Pooled escrows (see Contributions)
     pub ngo: Address,
+    pub token: Address,            // SEP-41 asset held in custody (e.g. native XLM SAC)
+    pub kind: EscrowKind,
     pub total_amount: i128,
     pub locked_amount: i128,       // 50% held in escrow
+    pub released_amount: i128,     // Paid out to the NGO so far (partial milestone releases)
     pub status: EscrowStatus,
     pub task_id: String,
     pub created_at: u64,           // ledger timestamp
     pub deadline: u64,             // task completion deadline
     pub dispute_start: u64,        // when dispute was initiated (0 if none)
-    pub dispute_lock_days: u64,    // 30 days in ledger time
+    pub policy: EscrowPolicy,      // thresholds captured at creation
     pub real_votes: u32,           // votes saying "real"
     pub scam_votes: u32,           // votes saying "scam"
+    pub real_weight: u32,          // weighted "real" tally (WEIGHT_UNIT per unweighted vote)
+    pub scam_weight: u32,          // weighted "scam" tally
+    pub vote_start: u64,           // when proof opened voting (0 = no proof yet)
+    pub commit_window: u64,        // commit–reveal commit phase length (0 = direct voting)
+    pub reveal_window: u64,        // commit–reveal reveal phase length
+    pub unrevealed_commits: u32,   // commitments not (yet) revealed
     pub proof_cid: String,         // IPFS CID of proof-of-work
+    pub proof_version: u32,        // number of proofs submitted (0 = none yet)
+    pub resubmit_mode: ResubmitMode,
     pub released_at: u64,          // when funds were released (0 if not)
+    pub milestones: Vec<Milestone>, // empty for Standard escrows
+}
+
+/// A donor's total contribution to a pooled escrow.
+#[derive(Clone, Debug)]
+#[contracttype]
+pub struct Contribution {
+    pub donor: Address,
+    pub amount: i128,
+}
+
+/// What a donation receipt attests to.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[contracttype]
+pub enum ReceiptKind {
+    Donation,    // Funds locked at creation (or a pooled contribution)
+    Settlement,  // Final outcome once the escrow settled
+}
+
+/// Non-transferable proof of giving. Held by this contract on the donor's
+/// behalf; there is no way to move it to another address.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub struct Receipt {
+    pub id: u32,
+    pub kind: ReceiptKind,
+    pub donor: Address,
+    pub task_id: String,
+    pub ngo: Address,
+    pub token: Address,
+    pub amount: i128,              // donor's escrowed amount (this contribution, for Donation)
+    pub refunded: i128,            // returned to the donor (Settlement only)
+    pub donated_at: u64,           // escrow creation
+    pub issued_at: u64,
+    pub outcome: EscrowStatus,     // Locked for Donation; final status for Settlement
 }
 
 /// Vote record to prevent double-voting
@@ -52,6 +268,70 @@ pub struct VoteRecord {
     pub voter: Address,
     pub is_scam: bool,
     pub timestamp: u64,
+    pub proof_version: u32,        // proof judged (0 = cast before any proof, or a milestone vote)
+    pub delegators: Vec<Address>,  // voters counted through this vote (1 + len votes)
+    pub weight: u32,               // weight counted for this vote, delegators included
+    pub delegator_weights: Vec<u32>, // weight of each delegator, in `delegators` order
+    pub bonded: i128,              // bond locked for this vote, one unit per voter counted (0 = unbonded)
+}
+
+/// Where an escrow's vote round stands (the current milestone's, for milestone escrows).
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[contracttype]
+pub enum VotingPhase {
+    AwaitingProof,  // No proof yet; votes are rejected
+    Open,           // Direct votes accepted
+    Commit,         // Commit–reveal: sealed votes accepted
+    Reveal,         // Commit–reveal: commitments being revealed
+    Final,          // Window over; the tally is final and settlement may run
+    Closed,         // Escrow no longer Locked
+}
+
+/// Voting window boundaries as ledger timestamps (0 = not applicable).
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub struct VotingWindows {
+    pub vote_start: u64,           // when proof opened voting
+    pub vote_end: u64,             // direct voting closes (0 = open until settled)
+    pub commit_end: u64,           // commit–reveal commit phase ends
+    pub reveal_end: u64,           // commit–reveal reveal phase ends
+}
+
+/// One entry in an escrow's proof history.
+#[derive(Clone, Debug)]
+#[contracttype]
+pub struct ProofSubmission {
+    pub version: u32,
+    pub cid: String,
+    pub submitted_at: u64,
+}
+
+/// Sealed vote for commit–reveal escrows.
+/// `commitment` = sha256(voter || task_id || round || choice_byte || salt), with the
+/// voter and task_id XDR-encoded, round the big-endian proof version and
+/// choice_byte = 1 for scam, 0 for real. A commitment copied from another voter
+/// or escrow therefore cannot be revealed.
+#[derive(Clone, Debug)]
+#[contracttype]
+pub struct VoteCommit {
+    pub voter: Address,
+    pub commitment: BytesN<32>,
+    pub timestamp: u64,
+    pub revealed: bool,
+}
+
+/// NGO appeal against a dispute, decided by the arbitrator panel.
+#[derive(Clone, Debug)]
+#[contracttype]
+pub struct Appeal {
+    pub evidence_cids: Vec<String>,  // IPFS CIDs of counter-evidence
+    pub filed_at: u64,
+    pub restore_votes: u32,          // arbitrators siding with the NGO
+    pub reject_votes: u32,           // arbitrators confirming the refund
+    pub arbitrators: Vec<Address>,   // who has voted so far
+    pub panel: Vec<Address>,         // arbitrator set when the appeal was filed
+    pub deadline: u64,               // undecided by then → `refund` may run
+    pub resolved_at: u64,            // 0 while pending
 }
 
 /// Leaderboard entry for accurate voters
@@ -61,6 +341,136 @@ pub struct VoterAccuracy {
     pub correct_votes: u32,
     pub total_votes: u32,
     pub rewards_earned: i128,
+    pub net_bond: i128,            // Bond winnings minus slashed bonds
+}
+
+/// Where voter rewards come from.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[contracttype]
+pub enum RewardSource {
+    Mint,   // Escrow is an authorized Reiatsu minter
+    Pool,   // Escrow pays from the Reiatsu deposited via `fund_reward_pool`
+}
+
+/// Admin-configured Reiatsu reward for correct voters.
+#[derive(Clone, Debug)]
+#[contracttype]
+pub struct RewardConfig {
+    pub token: Address,            // Reiatsu token contract
+    pub amount: i128,              // Reward per correct vote
+    pub source: RewardSource,
+}
+
+/// Reaper status as stored by soul-reaper-registry.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[contracttype]
+pub enum ReaperStatus {
+    Active,
+    Suspended,
+}
+
+/// Mirror of soul-reaper-registry's `SoulReaper`, decoded from `get_reaper`.
+#[derive(Clone, Debug)]
+#[contracttype]
+pub struct SoulReaper {
+    pub name: String,
+    pub division: u32,
+    pub rank: Symbol,
+    pub power_level: u32,
+    pub owner: Address,
+    pub status: ReaperStatus,
+    pub joined_at: u64,
+}
+
+/// Admin-configured voter eligibility. When set, only active registered
+/// reapers (with at least `min_badges` Soul Badges, if a badge contract is set) may vote.
+#[derive(Clone, Debug)]
+#[contracttype]
+pub struct VoterGate {
+    pub registry: Address,               // soul-reaper-registry contract
+    pub badge_contract: Option<Address>, // soul-badge contract (None = no badge check)
+    pub min_badges: u32,
+}
+
+/// How much each vote counts toward the release/dispute shares.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[contracttype]
+pub enum VoteWeighting {
+    Flat,       // Every vote weighs WEIGHT_UNIT
+    Accuracy,   // Voter's correct/total ratio, floored for newcomers
+    Badges,     // Voter's Soul Badge tier (needs a voter gate badge contract)
+    Both,       // Accuracy scaled by badge tier
+}
+
+/// How the platform fee reaches the treasury.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[contracttype]
+pub enum FeeRoute {
+    Transfer,   // Plain token transfer to the treasury address
+    Deposit,    // Transfer, then record it via `DivisionTreasury::deposit`
+}
+
+/// Admin-configured platform fee on escrow settlements.
+#[derive(Clone, Debug)]
+#[contracttype]
+pub struct FeeConfig {
+    pub treasury: Address,
+    pub fee_bps: u32,              // basis points of each payout (max MAX_FEE_BPS)
+    pub on_refund: bool,           // also charge refunds, not just releases
+    pub route: FeeRoute,
+}
+
+/// Per-escrow bonded voting. Each vote locks `amount` of `token` (Reiatsu);
+/// incorrect voters forfeit `slash_pct`% of it to the correct voters.
+#[derive(Clone, Debug)]
+#[contracttype]
+pub struct VoteBond {
+    pub token: Address,
+    pub amount: i128,
+    pub slash_pct: u32,
+}
+
+/// Which escrow index to read: every escrow, or those of one NGO / donor.
+#[derive(Clone, Debug)]
+#[contracttype]
+pub enum IndexScope {
+    All,
+    Ngo(Address),
+    Donor(Address),   // includes pooled escrows the address contributed to
+}
+
+/// One page of escrow records. Pass `next_cursor` back to continue;
+/// the scan is finished once `next_cursor == total`.
+#[derive(Clone, Debug)]
+#[contracttype]
+pub struct EscrowPage {
+    pub escrows: Vec<EscrowData>,
+    pub next_cursor: u32,
+    pub total: u32,
+}
+
+/// Escrow track record of an NGO. `trust_score` (0–100) is the share of settled
+/// value that was released rather than refunded; 50 until anything settles.
+/// Donor releases skip the vote, so they are counted apart and do not raise it.
+#[derive(Clone, Debug)]
+#[contracttype]
+pub struct NgoReputation {
+    pub released_count: u32,       // voted/arbitrated release payouts (each milestone slice counts)
+    pub disputed_count: u32,
+    pub refunded_count: u32,
+    pub donor_released_count: u32, // early releases by the donor
+    pub total_released: i128,
+    pub total_refunded: i128,
+    pub total_donor_released: i128,
+    pub trust_score: u32,
+}
+
+/// Admin rule: escrows locking at least `min_amount` need an NGO trust score of `min_score`.
+#[derive(Clone, Debug)]
+#[contracttype]
+pub struct ReputationGate {
+    pub min_score: u32,
+    pub min_amount: i128,
 }
 
 // ── Storage Keys ─────────────────────────────────────────────────
@@ -71,22 +481,59 @@ pub enum DataKey {
     Admin,
     Escrow(String),               // task_id → EscrowData
     Votes(String),                // task_id → Vec<VoteRecord>
+    Commits(String),              // task_id → Vec<VoteCommit>
+    MilestoneVotes(String, u32),  // (task_id, milestone) → Vec<VoteRecord>
     VoterStats(Address),          // voter → VoterAccuracy
-    EscrowIndex,                  // → Vec<String> (all task_ids)
-    NgoEscrows(Address),          // ngo → Vec<String> (their task_ids)
-    DonorEscrows(Address),        // donor → Vec<String>
+    IndexBucket(IndexScope, u32), // (scope, bucket) → Vec<String> of up to INDEX_BUCKET_SIZE task_ids
+    IndexLen(IndexScope),         // scope → number of indexed task_ids
+    AcceptedToken(Address),       // token → true while the admin accepts it for new escrows
     TotalLocked,                  // Global total locked amount
     TotalReleased,                // Global total released amount
     TotalRefunded,                // Global total refunded amount
+    TotalFees,                    // Global total platform fees collected
+    FeeConfig,                    // Platform fee settings
     DisputeThreshold,             // Vote % to trigger dispute (default 60)
     LockDuration,                 // Lock duration in ledger time (default ~30 days)
+    MinVotes,                     // Votes needed before a decision (default 3)
+    VoteWindow,                   // Direct voting period after proof (default 0 = open)
+    RewardConfig,                 // Reiatsu voter reward settings
+    Arbitrators,                  // → Vec<Address> appeal panel
+    Appeal(String),               // task_id → Appeal
+    VoterGate,                    // Reaper registry / badge requirements for voters
+    VoteWeighting,                // How votes are weighted (default Flat)
+    VoteBond(String),             // task_id → VoteBond
+    RewardPool,                   // Reiatsu held for RewardSource::Pool rewards, not yet paid out
+    Contributions(String),        // task_id → Vec<Contribution> (pooled escrows)
+    ProofHistory(String),         // task_id → Vec<ProofSubmission>
+    RoundVotes(String, u32),      // (task_id, proof_version) → Vec<VoteRecord> of a closed round
+    Reputation(Address),          // ngo → NgoReputation
+    ReputationGate,               // Minimum trust score for large escrows
+    Receipt(u32),                 // receipt id → Receipt
+    ReceiptCount,                 // Receipts issued so far (ids start at 1)
+    DonorReceiptBucket(Address, u32), // (donor, bucket) → Vec<u32> of up to INDEX_BUCKET_SIZE receipt ids
+    DonorReceiptCount(Address),   // donor → number of receipts issued to them
+    DonorHasReceipt(Address, String), // (donor, task_id) → true once a receipt is issued
+    Delegation(Address, Option<Address>), // (delegator, ngo or None = global) → delegate
+    Delegators(Address, Option<Address>), // (delegate, ngo or None = global) → Vec<Address>
+    PendingDelegation(Address, Option<Address>), // (delegator, ngo or None = global) → delegate yet to accept
 }
 
 // ── Constants ────────────────────────────────────────────────────
 
 const DEFAULT_DISPUTE_THRESHOLD: u32 = 60; // 60% scam votes to dispute
 const DEFAULT_LOCK_DURATION: u64 = 2_592_000; // ~30 days in seconds
-const MIN_VOTES_FOR_DECISION: u32 = 3;  // Minimum votes before resolving
+const DEFAULT_MIN_VOTES: u32 = 3;       // Minimum votes before resolving
+const DEFAULT_VOTE_WINDOW: u64 = 0;     // No fixed voting window
+const NEUTRAL_TRUST_SCORE: u32 = 50;    // Trust score before any escrow settles
+const MAX_FEE_BPS: u32 = 1_000;         // Platform fee cap (10%)
+const WEIGHT_UNIT: u32 = 100;           // Weight of one unweighted vote
+const NEWCOMER_VOTES: u32 = 5;          // Voters with fewer votes get at least ACCURACY_FLOOR
+const ACCURACY_FLOOR: u32 = 50;         // Newcomer accuracy weight floor
+const INDEX_BUCKET_SIZE: u32 = 50;      // task_ids per index storage entry
+const MAX_PAGE_SIZE: u32 = 50;          // index positions read per page query
+const MAX_DELEGATORS: u32 = 25;         // accepted delegators per delegate and scope
+const MAX_PROOF_ROUNDS: u32 = 3;        // proof submissions per escrow
+const ARBITRATION_WINDOW: u64 = 1_209_600; // ~14 days for the panel to decide an appeal
 
 // ── Contract ─────────────────────────────────────────────────────
 
@@ -99,396 +546,2821 @@ impl EscrowContract {
 
     // ── INITIALIZATION ──────────────────────────────────────────
 
-    pub fn initialize(env: Env, admin: Address) {
+    pub fn initialize(env: Env, admin: Address) -> Result<(), Error> {
         if env.storage().persistent().has(&DataKey::Admin) {
-            panic!("Already initialized");
+            return Err(Error::AlreadyInitialized);
         }
         admin.require_auth();
         env.storage().persistent().set(&DataKey::Admin, &admin);
         env.storage().persistent().set(&DataKey::TotalLocked, &0i128);
         env.storage().persistent().set(&DataKey::TotalReleased, &0i128);
         env.storage().persistent().set(&DataKey::TotalRefunded, &0i128);
+        env.storage().persistent().set(&DataKey::TotalFees, &0i128);
         env.storage().persistent().set(&DataKey::DisputeThreshold, &DEFAULT_DISPUTE_THRESHOLD);
         env.storage().persistent().set(&DataKey::LockDuration, &DEFAULT_LOCK_DURATION);
+        env.storage().persistent().set(&DataKey::MinVotes, &DEFAULT_MIN_VOTES);
+        env.storage().persistent().set(&DataKey::VoteWindow, &DEFAULT_VOTE_WINDOW);
 
-        let empty_index: Vec<String> = Vec::new(&env);
-        env.storage().persistent().set(&DataKey::EscrowIndex, &empty_index);
+        Ok(())
     }
 
-    // ── CREATE ESCROW ───────────────────────────────────────────
-
-    /// Creates a new escrow. The donor locks `locked_amount` (typically 50% of total).
-    /// The other 50% is sent directly to the NGO off-chain.
-    pub fn create_escrow(
-        env: Env,
-        donor: Address,
-        ngo: Address,
-        total_amount: i128,
-        locked_amount: i128,
-        task_id: String,
-        deadline: u64,
-    ) {
-        donor.require_auth();
+    // ── ADMIN CONFIG ────────────────────────────────────────────
 
-        if locked_amount <= 0 || total_amount <= 0 {
-            panic!("Amounts must be positive");
+    /// Set the global vote share (51–100%) needed to release or dispute.
+    pub fn set_dispute_threshold(env: Env, threshold: u32) -> Result<(), Error> {
+        Self::require_admin(&env)?;
+        if threshold <= 50 || threshold > 100 {
+            return Err(Error::InvalidConfig);
         }
-        if locked_amount > total_amount {
-            panic!("Locked amount cannot exceed total");
-        }
-        if env.storage().persistent().has(&DataKey::Escrow(task_id.clone())) {
-            panic!("Escrow already exists for this task");
-        }
-
-        let now = env.ledger().timestamp();
+        env.storage().persistent().set(&DataKey::DisputeThreshold, &threshold);
+        Self::publish_policy(&env);
+        Ok(())
+    }
 
-        let escrow = EscrowData {
-            donor: donor.clone(),
-            ngo: ngo.clone(),
-            total_amount,
-            locked_amount,
-            status: EscrowStatus::Locked,
-            task_id: task_id.clone(),
-            created_at: now,
-            deadline,
-            dispute_start: 0,
-            dispute_lock_days: DEFAULT_LOCK_DURATION,
-            real_votes: 0,
-            scam_votes: 0,
-            proof_cid: String::from_str(&env, ""),
-            released_at: 0,
-        };
+    /// Set the global dispute lock before a refund, in ledger seconds.
+    pub fn set_lock_duration(env: Env, duration: u64) -> Result<(), Error> {
+        Self::require_admin(&env)?;
+        if duration == 0 {
+            return Err(Error::InvalidConfig);
+        }
+        env.storage().persistent().set(&DataKey::LockDuration, &duration);
+        Self::publish_policy(&env);
+        Ok(())
+    }
 
-        env.storage().persistent().set(&DataKey::Escrow(task_id.clone()), &escrow);
+    /// Set the global minimum number of votes before an escrow can be decided.
+    pub fn set_min_votes(env: Env, min_votes: u32) -> Result<(), Error> {
+        Self::require_admin(&env)?;
+        if min_votes == 0 {
+            return Err(Error::InvalidConfig);
+        }
+        env.storage().persistent().set(&DataKey::MinVotes, &min_votes);
+        Self::publish_policy(&env);
+        Ok(())
+    }
 
-        // Initialize empty vote list
-        let empty_votes: Vec<VoteRecord> = Vec::new(&env);
-        env.storage().persistent().set(&DataKey::Votes(task_id.clone()), &empty_votes);
+    /// Set the global voting window that follows a proof submission, in ledger
+    /// seconds. Settlement waits for it to close; 0 leaves voting open until settled.
+    pub fn set_vote_window(env: Env, window: u64) -> Result<(), Error> {
+        Self::require_admin(&env)?;
+        env.storage().persistent().set(&DataKey::VoteWindow, &window);
+        Self::publish_policy(&env);
+        Ok(())
+    }
 
-        // Update indices
-        let mut index: Vec<String> = env.storage().persistent()
-            .get(&DataKey::EscrowIndex).unwrap_or(Vec::new(&env));
-        index.push_back(task_id.clone());
-        env.storage().persistent().set(&DataKey::EscrowIndex, &index);
-
-        // Update NGO escrows
-        let mut ngo_escrows: Vec<String> = env.storage().persistent()
-            .get(&DataKey::NgoEscrows(ngo.clone())).unwrap_or(Vec::new(&env));
-        ngo_escrows.push_back(task_id.clone());
-        env.storage().persistent().set(&DataKey::NgoEscrows(ngo.clone()), &ngo_escrows);
-
-        // Update donor escrows
-        let mut donor_escrows: Vec<String> = env.storage().persistent()
-            .get(&DataKey::DonorEscrows(donor.clone())).unwrap_or(Vec::new(&env));
-        donor_escrows.push_back(task_id.clone());
-        env.storage().persistent().set(&DataKey::DonorEscrows(donor.clone()), &donor_escrows);
+    /// Set the global Reiatsu reward per correct vote (rewards must be configured).
+    pub fn set_voter_reward(env: Env, amount: i128) -> Result<(), Error> {
+        Self::require_admin(&env)?;
+        if amount < 0 {
+            return Err(Error::InvalidAmount);
+        }
+        let mut config: RewardConfig = env.storage().persistent()
+            .get(&DataKey::RewardConfig).ok_or(Error::RewardsNotConfigured)?;
+        config.amount = amount;
+        env.storage().persistent().set(&DataKey::RewardConfig, &config);
+        Self::publish_policy(&env);
+        Ok(())
+    }
 
-        // Update global locked total
-        let total_locked: i128 = env.storage().persistent()
-            .get(&DataKey::TotalLocked).unwrap_or(0);
-        env.storage().persistent().set(&DataKey::TotalLocked, &(total_locked + locked_amount));
+    /// Accept or stop accepting `token` for new escrows. Every accepted token must
+    /// use the same accounting unit (e.g. one USD stablecoin precision), since
+    /// stats, reputation and the reputation gate add amounts across escrows.
+    /// Escrows already open keep settling in their token.
+    pub fn set_token_accepted(env: Env, token: Address, accepted: bool) -> Result<(), Error> {
+        Self::require_admin(&env)?;
+        if accepted {
+            env.storage().persistent().set(&DataKey::AcceptedToken(token.clone()), &true);
+        } else {
+            env.storage().persistent().remove(&DataKey::AcceptedToken(token.clone()));
+        }
 
         env.events().publish(
-            (symbol_short!("escrow"), symbol_short!("created")),
-            (task_id, donor, ngo, locked_amount),
+            (symbol_short!("escrow"), symbol_short!("token_set")),
+            (token, accepted),
         );
+
+        Ok(())
     }
 
-    // ── SUBMIT PROOF ────────────────────────────────────────────
+    /// Require a minimum NGO trust score for escrows locking `min_amount` or more.
+    pub fn set_reputation_gate(env: Env, min_score: u32, min_amount: i128) -> Result<(), Error> {
+        Self::require_admin(&env)?;
+        if min_score > 100 || min_amount <= 0 {
+            return Err(Error::InvalidConfig);
+        }
 
-    /// NGO submits proof of completed work (IPFS CID).
-    pub fn submit_proof(env: Env, task_id: String, proof_cid: String) {
-        let mut escrow: EscrowData = env.storage().persistent()
-            .get(&DataKey::Escrow(task_id.clone()))
-            .expect("Escrow not found");
+        env.storage().persistent()
+            .set(&DataKey::ReputationGate, &ReputationGate { min_score, min_amount });
 
-        escrow.ngo.require_auth();
+        env.events().publish(
+            (symbol_short!("escrow"), symbol_short!("rep_gate")),
+            (min_score, min_amount),
+        );
 
-        if escrow.status != EscrowStatus::Locked {
-            panic!("Escrow is not in Locked state");
+        Ok(())
+    }
+
+    /// Charge `fee_bps` on every release (and refund, if `on_refund`) and send it
+    /// to `treasury`. With `FeeRoute::Deposit`, `treasury` must be a division
+    /// treasury contract; the fee is recorded there under this contract's address.
+    pub fn set_fee_config(env: Env, treasury: Address, fee_bps: u32, on_refund: bool, route: FeeRoute) -> Result<(), Error> {
+        Self::require_admin(&env)?;
+        if fee_bps > MAX_FEE_BPS {
+            return Err(Error::InvalidConfig);
         }
 
-        escrow.proof_cid = proof_cid;
-        env.storage().persistent().set(&DataKey::Escrow(task_id.clone()), &escrow);
+        let config = FeeConfig { treasury, fee_bps, on_refund, route };
+        env.storage().persistent().set(&DataKey::FeeConfig, &config);
 
         env.events().publish(
-            (symbol_short!("escrow"), symbol_short!("proof")),
-            task_id,
+            (symbol_short!("escrow"), symbol_short!("fee_cfg")),
+            (config.treasury, fee_bps, on_refund),
         );
-    }
 
-    // ── COMMUNITY VOTE ──────────────────────────────────────────
+        Ok(())
+    }
 
-    /// Community member votes on whether the task is real or a scam.
-    /// Cannot vote on own escrow. Cannot double-vote.
-    pub fn vote(env: Env, task_id: String, voter: Address, is_scam: bool) {
-        voter.require_auth();
+    /// Stop charging platform fees.
+    pub fn clear_fee_config(env: Env) -> Result<(), Error> {
+        Self::require_admin(&env)?;
+        if !env.storage().persistent().has(&DataKey::FeeConfig) {
+            return Err(Error::FeesNotConfigured);
+        }
+        env.storage().persistent().remove(&DataKey::FeeConfig);
+        Ok(())
+    }
 
-        let mut escrow: EscrowData = env.storage().persistent()
-            .get(&DataKey::Escrow(task_id.clone()))
-            .expect("Escrow not found");
+    /// Configure Reiatsu voter rewards paid on `release`/`refund`.
+    /// With `RewardSource::Mint` the escrow must be an authorized minter on `token`;
+    /// with `RewardSource::Pool` rewards come from the reward pool.
+    pub fn set_reward_config(env: Env, token: Address, amount: i128, source: RewardSource) -> Result<(), Error> {
+        Self::require_admin(&env)?;
 
-        if escrow.status != EscrowStatus::Locked {
-            panic!("Escrow is not in Locked state");
+        if amount < 0 {
+            return Err(Error::InvalidAmount);
         }
 
-        // Prevent donor/NGO from voting on their own escrow
-        if voter == escrow.donor || voter == escrow.ngo {
-            panic!("Cannot vote on own escrow");
-        }
+        let config = RewardConfig { token, amount, source };
+        env.storage().persistent().set(&DataKey::RewardConfig, &config);
 
-        // Check for double voting
-        let mut votes: Vec<VoteRecord> = env.storage().persistent()
-            .get(&DataKey::Votes(task_id.clone())).unwrap_or(Vec::new(&env));
+        env.events().publish(
+            (symbol_short!("escrow"), symbol_short!("rwd_cfg")),
+            (config.token, amount, source),
+        );
 
-        for i in 0..votes.len() {
-            if votes.get(i).unwrap().voter == voter {
-                panic!("Already voted");
-            }
-        }
+        Ok(())
+    }
 
-        // Record vote
-        let vote = VoteRecord {
-            voter: voter.clone(),
-            is_scam,
-            timestamp: env.ledger().timestamp(),
-        };
-        votes.push_back(vote);
-        env.storage().persistent().set(&DataKey::Votes(task_id.clone()), &votes);
+    /// Top up the Reiatsu reward pool used with `RewardSource::Pool`. Only funds
+    /// deposited here are ever paid out as rewards, never escrowed donations.
+    pub fn fund_reward_pool(env: Env, funder: Address, amount: i128) -> Result<(), Error> {
+        funder.require_auth();
 
-        // Update escrow vote counts
-        if is_scam {
-            escrow.scam_votes += 1;
-        } else {
-            escrow.real_votes += 1;
+        let config: RewardConfig = env.storage().persistent()
+            .get(&DataKey::RewardConfig).ok_or(Error::RewardsNotConfigured)?;
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
         }
-        env.storage().persistent().set(&DataKey::Escrow(task_id.clone()), &escrow);
 
-        // Update voter stats
-        let mut stats: VoterAccuracy = env.storage().persistent()
-            .get(&DataKey::VoterStats(voter.clone()))
-            .unwrap_or(VoterAccuracy {
-                correct_votes: 0,
-                total_votes: 0,
-                rewards_earned: 0,
-            });
-        stats.total_votes += 1;
-        env.storage().persistent().set(&DataKey::VoterStats(voter.clone()), &stats);
+        token::TokenClient::new(&env, &config.token)
+            .transfer(&funder, env.current_contract_address(), &amount);
+        let pool: i128 = env.storage().persistent()
+            .get(&DataKey::RewardPool).unwrap_or(0);
+        env.storage().persistent().set(&DataKey::RewardPool, &(pool + amount));
 
         env.events().publish(
-            (symbol_short!("escrow"), symbol_short!("vote")),
-            (task_id, voter, is_scam),
+            (symbol_short!("escrow"), symbol_short!("rwd_fund")),
+            (funder, amount),
         );
-    }
 
-    // ── RELEASE ESCROW ──────────────────────────────────────────
+        Ok(())
+    }
 
-    /// Release locked funds to the NGO after community verification.
-    /// Requires: status == Locked, real_votes > scam_votes, min votes met.
-    pub fn release(env: Env, task_id: String) {
-        let admin: Address = env.storage().persistent()
-            .get(&DataKey::Admin).expect("Not initialized");
-        admin.require_auth();
+    /// Set the arbitrator panel that decides NGO appeals.
+    pub fn set_arbitrators(env: Env, arbitrators: Vec<Address>) -> Result<(), Error> {
+        Self::require_admin(&env)?;
 
-        let mut escrow: EscrowData = env.storage().persistent()
-            .get(&DataKey::Escrow(task_id.clone()))
-            .expect("Escrow not found");
+        env.storage().persistent().set(&DataKey::Arbitrators, &arbitrators);
 
-        if escrow.status != EscrowStatus::Locked {
-            panic!("Escrow is not in Locked state");
-        }
+        env.events().publish(
+            (symbol_short!("escrow"), symbol_short!("arb_set")),
+            arbitrators.len(),
+        );
 
-        let total_votes = escrow.real_votes + escrow.scam_votes;
-        if total_votes < MIN_VOTES_FOR_DECISION {
-            panic!("Not enough votes to make a decision");
-        }
+        Ok(())
+    }
 
-        let real_pct = (escrow.real_votes as u64 * 100) / total_votes as u64;
-        let threshold = env.storage().persistent()
-            .get(&DataKey::DisputeThreshold).unwrap_or(DEFAULT_DISPUTE_THRESHOLD);
+    /// Restrict voting to active reapers in `registry`, optionally requiring
+    /// `min_badges` badges from `badge_contract`.
+    pub fn set_voter_gate(env: Env, registry: Address, badge_contract: Option<Address>, min_badges: u32) -> Result<(), Error> {
+        Self::require_admin(&env)?;
 
-        if real_pct < threshold as u64 {
-            panic!("Not enough real votes to release");
-        }
+        let gate = VoterGate { registry, badge_contract, min_badges };
+        env.storage().persistent().set(&DataKey::VoterGate, &gate);
 
-        escrow.status = EscrowStatus::Released;
-        escrow.released_at = env.ledger().timestamp();
-        env.storage().persistent().set(&DataKey::Escrow(task_id.clone()), &escrow);
+        env.events().publish(
+            (symbol_short!("escrow"), symbol_short!("gate_set")),
+            (gate.registry, min_badges),
+        );
 
-        // Update global stats
-        let total_locked: i128 = env.storage().persistent()
-            .get(&DataKey::TotalLocked).unwrap_or(0);
-        env.storage().persistent().set(&DataKey::TotalLocked,
-            &(total_locked - escrow.locked_amount));
+        Ok(())
+    }
 
-        let total_released: i128 = env.storage().persistent()
-            .get(&DataKey::TotalReleased).unwrap_or(0);
-        env.storage().persistent().set(&DataKey::TotalReleased,
-            &(total_released + escrow.locked_amount));
+    /// Choose how votes are weighted. Badge-based modes need a voter gate
+    /// with a badge contract. Votes already cast keep their weight.
+    pub fn set_vote_weighting(env: Env, mode: VoteWeighting) -> Result<(), Error> {
+        Self::require_admin(&env)?;
 
-        // Reward accurate voters
-        Self::reward_voters(&env, &task_id, false);
+        if mode == VoteWeighting::Badges || mode == VoteWeighting::Both {
+            let gate: Option<VoterGate> = env.storage().persistent().get(&DataKey::VoterGate);
+            if gate.and_then(|g| g.badge_contract).is_none() {
+                return Err(Error::InvalidConfig);
+            }
+        }
+        env.storage().persistent().set(&DataKey::VoteWeighting, &mode);
 
         env.events().publish(
-            (symbol_short!("escrow"), symbol_short!("release")),
-            (task_id, escrow.ngo, escrow.locked_amount),
+            (symbol_short!("escrow"), symbol_short!("wgt_set")),
+            mode,
         );
+
+        Ok(())
+    }
+
+    // ── CREATE ESCROW ───────────────────────────────────────────
+
+    /// Creates a new escrow. The donor locks `funding.locked_amount` (typically 50% of
+    /// the total), which is transferred from the donor into this contract in `token`.
+    /// The other 50% is sent directly to the NGO off-chain.
+    pub fn create_escrow(
+        env: Env,
+        donor: Address,
+        ngo: Address,
+        token: Address,
+        task_id: String,
+        funding: EscrowFunding,
+        policy: Option<EscrowPolicy>,
+    ) -> Result<(), Error> {
+        donor.require_auth();
+        let EscrowFunding { total_amount, locked_amount, deadline } = funding;
+
+        if donor == ngo {
+            return Err(Error::DonorIsNgo);
+        }
+        Self::require_accepted_token(&env, &token)?;
+        if locked_amount <= 0 || total_amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+        if locked_amount > total_amount {
+            return Err(Error::InvalidAmount);
+        }
+        if env.storage().persistent().has(&DataKey::Escrow(task_id.clone())) {
+            return Err(Error::EscrowAlreadyExists);
+        }
+
+        Self::check_reputation(&env, &ngo, locked_amount)?;
+        let policy = Self::resolve_policy(&env, policy)?;
+        let now = env.ledger().timestamp();
+
+        let escrow = EscrowData {
+            donor,
+            ngo,
+            token,
+            kind: EscrowKind::Standard,
+            total_amount,
+            locked_amount,
+            released_amount: 0,
+            status: EscrowStatus::Locked,
+            task_id,
+            created_at: now,
+            deadline,
+            dispute_start: 0,
+            policy,
+            real_votes: 0,
+            scam_votes: 0,
+            real_weight: 0,
+            scam_weight: 0,
+            vote_start: 0,
+            commit_window: 0,
+            reveal_window: 0,
+            unrevealed_commits: 0,
+            proof_cid: String::from_str(&env, ""),
+            proof_version: 0,
+            resubmit_mode: ResubmitMode::ResetTally,
+            released_at: 0,
+            milestones: Vec::new(&env),
+        };
+
+        Self::open_escrow(&env, &escrow);
+
+        Ok(())
+    }
+
+    /// Creates an escrow paid out in ordered milestones. The donor locks the sum of
+    /// all milestone amounts; each milestone is proven, voted on and released on its own.
+    pub fn create_milestone_escrow(
+        env: Env,
+        donor: Address,
+        ngo: Address,
+        token: Address,
+        task_id: String,
+        milestones: Vec<MilestoneSpec>,
+        policy: Option<EscrowPolicy>,
+    ) -> Result<(), Error> {
+        donor.require_auth();
+
+        if donor == ngo {
+            return Err(Error::DonorIsNgo);
+        }
+        Self::require_accepted_token(&env, &token)?;
+        if milestones.is_empty() {
+            return Err(Error::InvalidMilestones);
+        }
+        if env.storage().persistent().has(&DataKey::Escrow(task_id.clone())) {
+            return Err(Error::EscrowAlreadyExists);
+        }
+
+        let mut total: i128 = 0;
+        let mut last_deadline: u64 = 0;
+        let mut stored: Vec<Milestone> = Vec::new(&env);
+        for i in 0..milestones.len() {
+            let spec = milestones.get(i).unwrap();
+            if spec.amount <= 0 {
+                return Err(Error::InvalidAmount);
+            }
+            if spec.deadline < last_deadline {
+                return Err(Error::InvalidMilestones);
+            }
+            total += spec.amount;
+            last_deadline = spec.deadline;
+            stored.push_back(Milestone {
+                amount: spec.amount,
+                deadline: spec.deadline,
+                status: MilestoneStatus::Pending,
+                proof_cid: String::from_str(&env, ""),
+                real_votes: 0,
+                scam_votes: 0,
+                real_weight: 0,
+                scam_weight: 0,
+                vote_start: 0,
+                released_at: 0,
+            });
+        }
+
+        Self::check_reputation(&env, &ngo, total)?;
+        let policy = Self::resolve_policy(&env, policy)?;
+        let now = env.ledger().timestamp();
+
+        let escrow = EscrowData {
+            donor,
+            ngo,
+            token,
+            kind: EscrowKind::Milestone,
+            total_amount: total,
+            locked_amount: total,
+            released_amount: 0,
+            status: EscrowStatus::Locked,
+            task_id,
+            created_at: now,
+            deadline: last_deadline,
+            dispute_start: 0,
+            policy,
+            real_votes: 0,
+            scam_votes: 0,
+            real_weight: 0,
+            scam_weight: 0,
+            vote_start: 0,
+            commit_window: 0,
+            reveal_window: 0,
+            unrevealed_commits: 0,
+            proof_cid: String::from_str(&env, ""),
+            proof_version: 0,
+            resubmit_mode: ResubmitMode::ResetTally,
+            released_at: 0,
+            milestones: stored,
+        };
+
+        Self::open_escrow(&env, &escrow);
+
+        Ok(())
+    }
+
+    /// Creates a pooled escrow that any donor can top up with `contribute` until
+    /// `funding.total_amount` (the target) is reached, proof is submitted or voting
+    /// starts, or the deadline passes. The creator opens it with the first
+    /// contribution of `funding.locked_amount`.
+    pub fn create_pool_escrow(
+        env: Env,
+        donor: Address,
+        ngo: Address,
+        token: Address,
+        task_id: String,
+        funding: EscrowFunding,
+        policy: Option<EscrowPolicy>,
+    ) -> Result<(), Error> {
+        donor.require_auth();
+        let EscrowFunding { total_amount: target, locked_amount: initial_amount, deadline } = funding;
+
+        if donor == ngo {
+            return Err(Error::DonorIsNgo);
+        }
+        Self::require_accepted_token(&env, &token)?;
+        if initial_amount <= 0 || target <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+        if initial_amount > target {
+            return Err(Error::ExceedsTarget);
+        }
+        if env.storage().persistent().has(&DataKey::Escrow(task_id.clone())) {
+            return Err(Error::EscrowAlreadyExists);
+        }
+
+        Self::check_reputation(&env, &ngo, target)?;
+        let policy = Self::resolve_policy(&env, policy)?;
+        let now = env.ledger().timestamp();
+
+        let escrow = EscrowData {
+            donor: donor.clone(),
+            ngo,
+            token,
+            kind: EscrowKind::Pooled,
+            total_amount: target,
+            locked_amount: initial_amount,
+            released_amount: 0,
+            status: EscrowStatus::Locked,
+            task_id: task_id.clone(),
+            created_at: now,
+            deadline,
+            dispute_start: 0,
+            policy,
+            real_votes: 0,
+            scam_votes: 0,
+            real_weight: 0,
+            scam_weight: 0,
+            vote_start: 0,
+            commit_window: 0,
+            reveal_window: 0,
+            unrevealed_commits: 0,
+            proof_cid: String::from_str(&env, ""),
+            proof_version: 0,
+            resubmit_mode: ResubmitMode::ResetTally,
+            released_at: 0,
+            milestones: Vec::new(&env),
+        };
+
+        let contributions = Vec::from_array(&env, [Contribution { donor, amount: initial_amount }]);
+        env.storage().persistent().set(&DataKey::Contributions(task_id), &contributions);
+
+        Self::open_escrow(&env, &escrow);
+
+        Ok(())
+    }
+
+    /// Add funds to a pooled escrow. Repeat contributions accumulate.
+    pub fn contribute(env: Env, task_id: String, donor: Address, amount: i128) -> Result<(), Error> {
+        donor.require_auth();
+
+        let mut escrow: EscrowData = env.storage().persistent()
+            .get(&DataKey::Escrow(task_id.clone()))
+            .ok_or(Error::EscrowNotFound)?;
+
+        if escrow.kind != EscrowKind::Pooled {
+            return Err(Error::WrongEscrowKind);
+        }
+        if escrow.status != EscrowStatus::Locked {
+            return Err(Error::NotLocked);
+        }
+        if donor == escrow.ngo {
+            return Err(Error::DonorIsNgo);
+        }
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+        let commits: Vec<VoteCommit> = env.storage().persistent()
+            .get(&DataKey::Commits(task_id.clone())).unwrap_or(Vec::new(&env));
+        if env.ledger().timestamp() > escrow.deadline
+            || !escrow.proof_cid.is_empty()
+            || escrow.real_votes + escrow.scam_votes > 0
+            || !commits.is_empty()
+        {
+            return Err(Error::FundingClosed);
+        }
+        if escrow.locked_amount + amount > escrow.total_amount {
+            return Err(Error::ExceedsTarget);
+        }
+
+        let mut contributions: Vec<Contribution> = env.storage().persistent()
+            .get(&DataKey::Contributions(task_id.clone())).unwrap_or(Vec::new(&env));
+        let mut existing: Option<u32> = None;
+        for i in 0..contributions.len() {
+            if contributions.get(i).unwrap().donor == donor {
+                existing = Some(i);
+                break;
+            }
+        }
+        match existing {
+            Some(i) => {
+                let mut c = contributions.get(i).unwrap();
+                c.amount += amount;
+                contributions.set(i, c);
+            }
+            None => {
+                contributions.push_back(Contribution { donor: donor.clone(), amount });
+                Self::index_push(&env, IndexScope::Donor(donor.clone()), &task_id);
+            }
+        }
+        env.storage().persistent().set(&DataKey::Contributions(task_id.clone()), &contributions);
+
+        escrow.locked_amount += amount;
+        env.storage().persistent().set(&DataKey::Escrow(task_id.clone()), &escrow);
+
+        let total_locked: i128 = env.storage().persistent()
+            .get(&DataKey::TotalLocked).unwrap_or(0);
+        env.storage().persistent().set(&DataKey::TotalLocked, &(total_locked + amount));
+
+        token::TokenClient::new(&env, &escrow.token)
+            .transfer(&donor, env.current_contract_address(), &amount);
+        Self::issue_receipt(&env, &escrow, &donor, ReceiptKind::Donation, amount, 0);
+
+        env.events().publish(
+            (symbol_short!("escrow"), symbol_short!("contrib")),
+            (task_id, donor, amount),
+        );
+
+        Ok(())
+    }
+
+    // ── SUBMIT PROOF ────────────────────────────────────────────
+
+    /// NGO submits proof of completed work (IPFS CID), opening the vote round.
+    /// Each submission is kept in the proof history; resubmitting closes the current
+    /// round according to the escrow's `ResubmitMode` and opens a fresh window.
+    /// Resubmission needs the voting window still open and a tally that has not
+    /// reached the release or dispute threshold, and is capped at MAX_PROOF_ROUNDS
+    /// submissions. Not allowed once commit–reveal commits exist.
+    pub fn submit_proof(env: Env, task_id: String, proof_cid: String) -> Result<(), Error> {
+        let mut escrow: EscrowData = env.storage().persistent()
+            .get(&DataKey::Escrow(task_id.clone()))
+            .ok_or(Error::EscrowNotFound)?;
+
+        escrow.ngo.require_auth();
+
+        Self::require_standard(&escrow)?;
+        if escrow.status != EscrowStatus::Locked {
+            return Err(Error::NotLocked);
+        }
+        if escrow.proof_version > 0 {
+            if escrow.proof_version >= MAX_PROOF_ROUNDS {
+                return Err(Error::TooManyProofRounds);
+            }
+            Self::require_voting_open(&env, &escrow.policy, escrow.vote_start)?;
+            let total_votes = escrow.real_votes + escrow.scam_votes;
+            if Self::require_release_quorum(&escrow.policy, total_votes, escrow.real_weight, escrow.scam_weight).is_ok()
+                || Self::require_dispute_quorum(&escrow.policy, total_votes, escrow.real_weight, escrow.scam_weight).is_ok()
+            {
+                return Err(Error::TallyDecided);
+            }
+            Self::close_proof_round(&env, &mut escrow)?;
+        }
+
+        escrow.proof_version += 1;
+        escrow.proof_cid = proof_cid.clone();
+        escrow.vote_start = env.ledger().timestamp();
+        env.storage().persistent().set(&DataKey::Escrow(task_id.clone()), &escrow);
+
+        let mut history: Vec<ProofSubmission> = env.storage().persistent()
+            .get(&DataKey::ProofHistory(task_id.clone())).unwrap_or(Vec::new(&env));
+        history.push_back(ProofSubmission {
+            version: escrow.proof_version,
+            cid: proof_cid,
+            submitted_at: env.ledger().timestamp(),
+        });
+        env.storage().persistent().set(&DataKey::ProofHistory(task_id.clone()), &history);
+
+        env.events().publish(
+            (symbol_short!("escrow"), symbol_short!("proof")),
+            (task_id, escrow.proof_version),
+        );
+
+        Ok(())
+    }
+
+    /// Donor chooses how votes are handled when the NGO resubmits proof.
+    pub fn set_resubmit_mode(env: Env, task_id: String, mode: ResubmitMode) -> Result<(), Error> {
+        let mut escrow: EscrowData = env.storage().persistent()
+            .get(&DataKey::Escrow(task_id.clone()))
+            .ok_or(Error::EscrowNotFound)?;
+
+        escrow.donor.require_auth();
+
+        Self::require_standard(&escrow)?;
+        if escrow.status != EscrowStatus::Locked {
+            return Err(Error::NotLocked);
+        }
+
+        escrow.resubmit_mode = mode;
+        env.storage().persistent().set(&DataKey::Escrow(task_id.clone()), &escrow);
+
+        env.events().publish(
+            (symbol_short!("escrow"), symbol_short!("resubmit")),
+            (task_id, mode),
+        );
+
+        Ok(())
+    }
+
+    // ── COMMUNITY VOTE ──────────────────────────────────────────
+
+    /// Community member votes on whether the task is real or a scam.
+    /// Only between the proof submission and the end of the voting window.
+    /// Cannot vote on own escrow. Cannot double-vote.
+    /// Not available on commit–reveal escrows (use `commit_vote`/`reveal_vote`).
+    pub fn vote(env: Env, task_id: String, voter: Address, is_scam: bool) -> Result<(), Error> {
+        voter.require_auth();
+
+        let mut escrow: EscrowData = env.storage().persistent()
+            .get(&DataKey::Escrow(task_id.clone()))
+            .ok_or(Error::EscrowNotFound)?;
+
+        Self::require_standard(&escrow)?;
+        Self::check_voter(&env, &escrow, &voter)?;
+
+        if escrow.commit_window > 0 {
+            return Err(Error::WrongVotingMode);
+        }
+        Self::require_voting_open(&env, &escrow.policy, escrow.vote_start)?;
+
+        Self::record_vote(&env, &task_id, &mut escrow, &voter, is_scam)?;
+        Self::lock_bond(&env, &task_id, &voter, 1);
+
+        Ok(())
+    }
+
+    // ── DELEGATION ──────────────────────────────────────────────
+
+    /// Offer to delegate escrow voting power to `delegate`, for escrows of `ngo`
+    /// only or, with `None`, for every NGO. A per-NGO delegation takes precedence
+    /// over the global one. The offer takes effect once the delegate accepts it
+    /// (`accept_delegation`); until then any earlier delegation in the scope stands.
+    pub fn delegate(env: Env, delegator: Address, delegate: Address, ngo: Option<Address>) -> Result<(), Error> {
+        delegator.require_auth();
+
+        if delegate == delegator {
+            return Err(Error::InvalidDelegate);
+        }
+
+        env.storage().persistent()
+            .set(&DataKey::PendingDelegation(delegator.clone(), ngo.clone()), &delegate);
+
+        env.events().publish(
+            (symbol_short!("escrow"), symbol_short!("delegate")),
+            (delegator, delegate, ngo),
+        );
+
+        Ok(())
+    }
+
+    /// Delegate accepts a pending delegation, replacing the delegator's previous
+    /// delegate in that scope. The delegator's voter eligibility is checked now and
+    /// again whenever their weight is counted; a delegate holds at most
+    /// MAX_DELEGATORS per scope.
+    pub fn accept_delegation(env: Env, delegate: Address, delegator: Address, ngo: Option<Address>) -> Result<(), Error> {
+        delegate.require_auth();
+
+        let pending_key = DataKey::PendingDelegation(delegator.clone(), ngo.clone());
+        let offered: Option<Address> = env.storage().persistent().get(&pending_key);
+        if offered != Some(delegate.clone()) {
+            return Err(Error::NotDelegated);
+        }
+
+        let list_key = DataKey::Delegators(delegate.clone(), ngo.clone());
+        let mut delegators: Vec<Address> = env.storage().persistent()
+            .get(&list_key).unwrap_or(Vec::new(&env));
+        if delegators.len() >= MAX_DELEGATORS {
+            return Err(Error::TooManyDelegators);
+        }
+        Self::check_reaper(&env, &delegator)?;
+
+        env.storage().persistent().remove(&pending_key);
+        let key = DataKey::Delegation(delegator.clone(), ngo.clone());
+        if let Some(previous) = env.storage().persistent().get::<DataKey, Address>(&key) {
+            Self::remove_delegator(&env, &previous, &ngo, &delegator);
+        }
+        env.storage().persistent().set(&key, &delegate);
+        delegators.push_back(delegator.clone());
+        env.storage().persistent().set(&list_key, &delegators);
+
+        env.events().publish(
+            (symbol_short!("escrow"), symbol_short!("deleg_ok")),
+            (delegator, delegate, ngo),
+        );
+
+        Ok(())
+    }
+
+    /// Delegate turns down a pending delegation, or drops an accepted one.
+    pub fn refuse_delegation(env: Env, delegate: Address, delegator: Address, ngo: Option<Address>) -> Result<(), Error> {
+        delegate.require_auth();
+
+        let pending_key = DataKey::PendingDelegation(delegator.clone(), ngo.clone());
+        let key = DataKey::Delegation(delegator.clone(), ngo.clone());
+        if env.storage().persistent().get::<DataKey, Address>(&pending_key) == Some(delegate.clone()) {
+            env.storage().persistent().remove(&pending_key);
+        } else if env.storage().persistent().get::<DataKey, Address>(&key) == Some(delegate.clone()) {
+            env.storage().persistent().remove(&key);
+            Self::remove_delegator(&env, &delegate, &ngo, &delegator);
+        } else {
+            return Err(Error::NotDelegated);
+        }
+
+        env.events().publish(
+            (symbol_short!("escrow"), symbol_short!("deleg_no")),
+            (delegator, delegate, ngo),
+        );
+
+        Ok(())
+    }
+
+    /// Revoke a delegation (and any pending offer in the same scope). Votes already
+    /// counted for open escrows stay counted unless the delegator votes on them directly.
+    pub fn revoke_delegation(env: Env, delegator: Address, ngo: Option<Address>) -> Result<(), Error> {
+        delegator.require_auth();
+
+        let pending_key = DataKey::PendingDelegation(delegator.clone(), ngo.clone());
+        let pending: Option<Address> = env.storage().persistent().get(&pending_key);
+        env.storage().persistent().remove(&pending_key);
+
+        let key = DataKey::Delegation(delegator.clone(), ngo.clone());
+        let delegate: Address = match env.storage().persistent().get(&key) {
+            Some(d) => d,
+            None => pending.ok_or(Error::NotDelegated)?,
+        };
+        if env.storage().persistent().has(&key) {
+            env.storage().persistent().remove(&key);
+            Self::remove_delegator(&env, &delegate, &ngo, &delegator);
+        }
+
+        env.events().publish(
+            (symbol_short!("escrow"), symbol_short!("undeleg")),
+            (delegator, delegate, ngo),
+        );
+
+        Ok(())
+    }
+
+    // ── BONDED VOTING ───────────────────────────────────────────
+
+    /// Donor requires every vote on this escrow to lock a Reiatsu bond
+    /// (the configured reward token). Must be set before any vote is cast.
+    pub fn set_vote_bond(env: Env, task_id: String, amount: i128, slash_pct: u32) -> Result<(), Error> {
+        let escrow: EscrowData = env.storage().persistent()
+            .get(&DataKey::Escrow(task_id.clone()))
+            .ok_or(Error::EscrowNotFound)?;
+
+        escrow.donor.require_auth();
+
+        Self::require_standard(&escrow)?;
+        if escrow.status != EscrowStatus::Locked {
+            return Err(Error::NotLocked);
+        }
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+        if slash_pct > 100 {
+            return Err(Error::InvalidConfig);
+        }
+        let commits: Vec<VoteCommit> = env.storage().persistent()
+            .get(&DataKey::Commits(task_id.clone())).unwrap_or(Vec::new(&env));
+        if escrow.real_votes + escrow.scam_votes > 0 || !commits.is_empty() {
+            return Err(Error::VotingStarted);
+        }
+        let config: RewardConfig = env.storage().persistent()
+            .get(&DataKey::RewardConfig).ok_or(Error::RewardsNotConfigured)?;
+
+        let bond = VoteBond { token: config.token, amount, slash_pct };
+        env.storage().persistent().set(&DataKey::VoteBond(task_id.clone()), &bond);
+
+        env.events().publish(
+            (symbol_short!("escrow"), symbol_short!("bond_set")),
+            (task_id, amount, slash_pct),
+        );
+
+        Ok(())
+    }
+
+    // ── COMMIT–REVEAL VOTING ────────────────────────────────────
+
+    /// Donor switches an escrow to commit–reveal voting before any vote is cast.
+    /// The commit phase starts now, or with the first proof if none is in yet;
+    /// the reveal phase follows it.
+    pub fn enable_commit_reveal(env: Env, task_id: String, commit_window: u64, reveal_window: u64) -> Result<(), Error> {
+        let mut escrow: EscrowData = env.storage().persistent()
+            .get(&DataKey::Escrow(task_id.clone()))
+            .ok_or(Error::EscrowNotFound)?;
+
+        escrow.donor.require_auth();
+
+        Self::require_standard(&escrow)?;
+        if escrow.status != EscrowStatus::Locked {
+            return Err(Error::NotLocked);
+        }
+        if commit_window == 0 || reveal_window == 0 {
+            return Err(Error::InvalidConfig);
+        }
+        let commits: Vec<VoteCommit> = env.storage().persistent()
+            .get(&DataKey::Commits(task_id.clone())).unwrap_or(Vec::new(&env));
+        if escrow.real_votes + escrow.scam_votes > 0 || !commits.is_empty() {
+            return Err(Error::VotingStarted);
+        }
+
+        if escrow.proof_version > 0 {
+            escrow.vote_start = env.ledger().timestamp();
+        }
+        escrow.commit_window = commit_window;
+        escrow.reveal_window = reveal_window;
+        env.storage().persistent().set(&DataKey::Escrow(task_id.clone()), &escrow);
+
+        env.events().publish(
+            (symbol_short!("escrow"), symbol_short!("cr_enable")),
+            (task_id, commit_window, reveal_window),
+        );
+
+        Ok(())
+    }
+
+    /// Submit a sealed vote during the commit phase.
+    /// `commitment` must equal `compute_commitment(voter, task_id, proof_version, is_scam, salt)`.
+    pub fn commit_vote(env: Env, task_id: String, voter: Address, commitment: BytesN<32>) -> Result<(), Error> {
+        voter.require_auth();
+
+        let mut escrow: EscrowData = env.storage().persistent()
+            .get(&DataKey::Escrow(task_id.clone()))
+            .ok_or(Error::EscrowNotFound)?;
+
+        Self::check_voter(&env, &escrow, &voter)?;
+
+        if escrow.commit_window == 0 {
+            return Err(Error::WrongVotingMode);
+        }
+        if escrow.vote_start == 0 {
+            return Err(Error::ProofRequired);
+        }
+        let now = env.ledger().timestamp();
+        if now >= escrow.vote_start + escrow.commit_window {
+            return Err(Error::WrongVotingPhase);
+        }
+
+        let mut commits: Vec<VoteCommit> = env.storage().persistent()
+            .get(&DataKey::Commits(task_id.clone())).unwrap_or(Vec::new(&env));
+        for i in 0..commits.len() {
+            if commits.get(i).unwrap().voter == voter {
+                return Err(Error::AlreadyVoted);
+            }
+        }
+
+        commits.push_back(VoteCommit {
+            voter: voter.clone(),
+            commitment,
+            timestamp: now,
+            revealed: false,
+        });
+        env.storage().persistent().set(&DataKey::Commits(task_id.clone()), &commits);
+
+        escrow.unrevealed_commits += 1;
+        env.storage().persistent().set(&DataKey::Escrow(task_id.clone()), &escrow);
+
+        // Bond is locked at commit time so skipping the reveal is not free
+        Self::lock_bond(&env, &task_id, &voter, 1);
+
+        env.events().publish(
+            (symbol_short!("escrow"), symbol_short!("commit")),
+            (task_id, voter),
+        );
+
+        Ok(())
+    }
+
+    /// Reveal a previously committed vote during the reveal phase.
+    /// Only revealed votes count toward `release`/`dispute`.
+    pub fn reveal_vote(env: Env, task_id: String, voter: Address, is_scam: bool, salt: BytesN<32>) -> Result<(), Error> {
+        voter.require_auth();
+
+        let mut escrow: EscrowData = env.storage().persistent()
+            .get(&DataKey::Escrow(task_id.clone()))
+            .ok_or(Error::EscrowNotFound)?;
+
+        if escrow.status != EscrowStatus::Locked {
+            return Err(Error::NotLocked);
+        }
+        if escrow.commit_window == 0 {
+            return Err(Error::WrongVotingMode);
+        }
+        let now = env.ledger().timestamp();
+        let commit_end = escrow.vote_start + escrow.commit_window;
+        if now < commit_end {
+            return Err(Error::WrongVotingPhase);
+        }
+        if now >= commit_end + escrow.reveal_window {
+            return Err(Error::WrongVotingPhase);
+        }
+
+        let mut commits: Vec<VoteCommit> = env.storage().persistent()
+            .get(&DataKey::Commits(task_id.clone())).unwrap_or(Vec::new(&env));
+        let mut idx: Option<u32> = None;
+        for i in 0..commits.len() {
+            if commits.get(i).unwrap().voter == voter {
+                idx = Some(i);
+                break;
+            }
+        }
+        let idx = idx.ok_or(Error::CommitmentNotFound)?;
+        let mut commit = commits.get(idx).unwrap();
+
+        if commit.revealed {
+            return Err(Error::AlreadyRevealed);
+        }
+        let expected = Self::compute_commitment(
+            env.clone(), voter.clone(), task_id.clone(), escrow.proof_version, is_scam, salt,
+        );
+        if commit.commitment != expected {
+            return Err(Error::CommitmentMismatch);
+        }
+
+        commit.revealed = true;
+        commits.set(idx, commit);
+        env.storage().persistent().set(&DataKey::Commits(task_id.clone()), &commits);
+
+        escrow.unrevealed_commits -= 1;
+        Self::record_vote(&env, &task_id, &mut escrow, &voter, is_scam)?;
+
+        Ok(())
+    }
+
+    /// Hash a vote choice with a salt, as expected by `commit_vote`. The hash is
+    /// bound to the voter, the escrow and its proof round (`proof_version`).
+    pub fn compute_commitment(
+        env: Env, voter: Address, task_id: String, round: u32, is_scam: bool, salt: BytesN<32>,
+    ) -> BytesN<32> {
+        let mut preimage = Bytes::new(&env);
+        preimage.append(&voter.to_xdr(&env));
+        preimage.append(&task_id.to_xdr(&env));
+        preimage.extend_from_array(&round.to_be_bytes());
+        preimage.push_back(if is_scam { 1 } else { 0 });
+        preimage.extend_from_array(&salt.to_array());
+        env.crypto().sha256(&preimage).into()
+    }
+
+    // ── RELEASE ESCROW ──────────────────────────────────────────
+
+    /// Release locked funds to the NGO after community verification.
+    /// Callable by anyone once the vote thresholds are met.
+    /// Requires: status == Locked, real_votes > scam_votes, min votes met.
+    pub fn release(env: Env, task_id: String) -> Result<(), Error> {
+        let mut escrow: EscrowData = env.storage().persistent()
+            .get(&DataKey::Escrow(task_id.clone()))
+            .ok_or(Error::EscrowNotFound)?;
+
+        Self::require_standard(&escrow)?;
+        if escrow.status != EscrowStatus::Locked {
+            return Err(Error::NotLocked);
+        }
+        Self::require_tally_final(&env, &escrow)?;
+        Self::require_release_quorum(&escrow.policy, escrow.real_votes + escrow.scam_votes, escrow.real_weight, escrow.scam_weight)?;
+
+        escrow.status = EscrowStatus::Released;
+        escrow.released_at = env.ledger().timestamp();
+        escrow.released_amount = escrow.locked_amount;
+        env.storage().persistent().set(&DataKey::Escrow(task_id.clone()), &escrow);
+
+        // Update global stats
+        let fee = Self::take_fee(&env, &escrow, escrow.locked_amount, false);
+        Self::record_release(&env, &escrow.ngo, escrow.locked_amount, fee, false);
+
+        // Settle bonds and reward accurate voters
+        Self::settle_bonds(&env, &task_id, Some(false));
+        Self::reward_voters(&env, &escrow, &DataKey::Votes(task_id.clone()), false);
+
+        // Pay out the locked funds to the NGO
+        Self::pay_out(&env, &escrow.token, &escrow.ngo, escrow.locked_amount - fee);
+        Self::issue_settlement_receipts(&env, &escrow, 0);
+
+        env.events().publish(
+            (symbol_short!("escrow"), symbol_short!("release")),
+            (task_id, escrow.ngo, escrow.locked_amount),
+        );
+
+        Ok(())
+    }
+
+    /// Donor releases the remaining locked funds to the NGO without waiting
+    /// for a vote, including while disputed. Not available on pooled escrows.
+    pub fn donor_release(env: Env, task_id: String) -> Result<(), Error> {
+        let mut escrow: EscrowData = env.storage().persistent()
+            .get(&DataKey::Escrow(task_id.clone()))
+            .ok_or(Error::EscrowNotFound)?;
+
+        escrow.donor.require_auth();
+
+        if escrow.kind == EscrowKind::Pooled {
+            return Err(Error::WrongEscrowKind);
+        }
+        if escrow.status != EscrowStatus::Locked && escrow.status != EscrowStatus::Disputed {
+            return Err(Error::NotLocked);
+        }
+
+        let amount = escrow.locked_amount - escrow.released_amount;
+        let now = env.ledger().timestamp();
+        for i in 0..escrow.milestones.len() {
+            let mut m = escrow.milestones.get(i).unwrap();
+            if m.status == MilestoneStatus::Pending || m.status == MilestoneStatus::Frozen {
+                m.status = MilestoneStatus::Released;
+                m.released_at = now;
+                escrow.milestones.set(i, m);
+            }
+        }
+
+        escrow.status = EscrowStatus::DonorReleased;
+        escrow.released_at = now;
+        escrow.released_amount = escrow.locked_amount;
+        env.storage().persistent().set(&DataKey::Escrow(task_id.clone()), &escrow);
+
+        // No verdict was reached: bonds go back in full and nobody is rewarded
+        let fee = Self::take_fee(&env, &escrow, amount, false);
+        Self::record_release(&env, &escrow.ngo, amount, fee, true);
+        Self::settle_bonds(&env, &task_id, None);
+        Self::pay_out(&env, &escrow.token, &escrow.ngo, amount - fee);
+        Self::issue_settlement_receipts(&env, &escrow, 0);
+
+        env.events().publish(
+            (symbol_short!("escrow"), symbol_short!("d_release")),
+            (task_id, escrow.ngo, amount),
+        );
+
+        Ok(())
+    }
+
+    // ── CANCEL ESCROW ───────────────────────────────────────────
+
+    /// Donor and NGO jointly cancel a locked escrow that has received no votes.
+    /// Locked funds go straight back to the donor (pro-rata for pooled escrows).
+    pub fn cancel(env: Env, task_id: String) -> Result<(), Error> {
+        let mut escrow: EscrowData = env.storage().persistent()
+            .get(&DataKey::Escrow(task_id.clone()))
+            .ok_or(Error::EscrowNotFound)?;
+
+        escrow.donor.require_auth();
+        escrow.ngo.require_auth();
+
+        if escrow.status != EscrowStatus::Locked {
+            return Err(Error::NotLocked);
+        }
+        let commits: Vec<VoteCommit> = env.storage().persistent()
+            .get(&DataKey::Commits(task_id.clone())).unwrap_or(Vec::new(&env));
+        let mut voted = escrow.real_votes + escrow.scam_votes > 0 || !commits.is_empty();
+        for i in 0..escrow.milestones.len() {
+            let m = escrow.milestones.get(i).unwrap();
+            voted = voted || m.real_votes + m.scam_votes > 0;
+        }
+        if voted {
+            return Err(Error::VotingStarted);
+        }
+
+        let amount = escrow.locked_amount - escrow.released_amount;
+        escrow.status = EscrowStatus::Cancelled;
+        env.storage().persistent().set(&DataKey::Escrow(task_id.clone()), &escrow);
+
+        let total_locked: i128 = env.storage().persistent()
+            .get(&DataKey::TotalLocked).unwrap_or(0);
+        env.storage().persistent().set(&DataKey::TotalLocked, &(total_locked - amount));
+
+        if escrow.kind == EscrowKind::Pooled {
+            Self::refund_contributors(&env, &escrow, amount);
+        } else {
+            Self::pay_out(&env, &escrow.token, &escrow.donor, amount);
+        }
+        Self::issue_settlement_receipts(&env, &escrow, amount);
+
+        env.events().publish(
+            (symbol_short!("escrow"), symbol_short!("cancel")),
+            (task_id, escrow.donor, amount),
+        );
+
+        Ok(())
     }
 
     // ── DISPUTE ESCROW ──────────────────────────────────────────
 
-    /// Initiate a dispute — starts the 30-day lock timer.
-    /// Requires: scam_votes > threshold and min votes met.
-    pub fn dispute(env: Env, task_id: String) {
+    /// Initiate a dispute — starts the 30-day lock timer.
+    /// Callable by anyone once the vote thresholds are met.
+    /// Requires: scam_votes > threshold and min votes met.
+    pub fn dispute(env: Env, task_id: String) -> Result<(), Error> {
+        let mut escrow: EscrowData = env.storage().persistent()
+            .get(&DataKey::Escrow(task_id.clone()))
+            .ok_or(Error::EscrowNotFound)?;
+
+        Self::require_standard(&escrow)?;
+        if escrow.status != EscrowStatus::Locked {
+            return Err(Error::NotLocked);
+        }
+        Self::require_tally_final(&env, &escrow)?;
+        Self::require_dispute_quorum(&escrow.policy, escrow.real_votes + escrow.scam_votes, escrow.real_weight, escrow.scam_weight)?;
+
+        escrow.status = EscrowStatus::Disputed;
+        escrow.dispute_start = env.ledger().timestamp();
+        env.storage().persistent().set(&DataKey::Escrow(task_id.clone()), &escrow);
+
+        let mut rep = Self::load_reputation(&env, &escrow.ngo);
+        rep.disputed_count += 1;
+        Self::save_reputation(&env, &escrow.ngo, rep);
+
+        env.events().publish(
+            (symbol_short!("escrow"), symbol_short!("dispute")),
+            (task_id, escrow.scam_votes, escrow.real_votes),
+        );
+
+        Ok(())
+    }
+
+    // ── REFUND ESCROW ───────────────────────────────────────────
+
+    /// Refund locked funds to the donor after the 30-day dispute lock,
+    /// or immediately once the escrow has expired. Callable by anyone.
+    /// For milestone escrows only the unreleased (frozen) remainder is refunded.
+    /// Requires: status == Disputed with lock period expired, status == Expired,
+    /// or status == Appealed with both the arbitration deadline and the dispute lock
+    /// passed (the dispute stands).
+    pub fn refund(env: Env, task_id: String) -> Result<(), Error> {
+        let mut escrow: EscrowData = env.storage().persistent()
+            .get(&DataKey::Escrow(task_id.clone()))
+            .ok_or(Error::EscrowNotFound)?;
+
+        let was_disputed = match escrow.status {
+            EscrowStatus::Disputed => {
+                let now = env.ledger().timestamp();
+                let lock_end = escrow.dispute_start + escrow.policy.lock_duration;
+                if now < lock_end {
+                    return Err(Error::LockNotExpired);
+                }
+                true
+            }
+            EscrowStatus::Expired => false,
+            EscrowStatus::Appealed => {
+                let mut appeal: Appeal = env.storage().persistent()
+                    .get(&DataKey::Appeal(task_id.clone()))
+                    .ok_or(Error::NotAppealed)?;
+                let now = env.ledger().timestamp();
+                if now < appeal.deadline {
+                    return Err(Error::AppealPending);
+                }
+                // An early appeal must not cut the dispute lock short
+                if now < escrow.dispute_start + escrow.policy.lock_duration {
+                    return Err(Error::LockNotExpired);
+                }
+                appeal.resolved_at = now;
+                env.storage().persistent().set(&DataKey::Appeal(task_id.clone()), &appeal);
+                env.events().publish(
+                    (symbol_short!("escrow"), symbol_short!("arb_exp")),
+                    task_id.clone(),
+                );
+                true
+            }
+            _ => return Err(Error::NotRefundable),
+        };
+
+        Self::settle_refund(&env, &mut escrow, was_disputed);
+
+        Ok(())
+    }
+
+    // ── APPEAL & ARBITRATION ────────────────────────────────────
+
+    /// NGO appeals a dispute with counter-evidence during the lock window.
+    /// The current arbitrator panel is fixed for this appeal. Blocks `refund`
+    /// until the panel decides or ARBITRATION_WINDOW passes.
+    pub fn appeal(env: Env, task_id: String, evidence_cids: Vec<String>) -> Result<(), Error> {
+        let mut escrow: EscrowData = env.storage().persistent()
+            .get(&DataKey::Escrow(task_id.clone()))
+            .ok_or(Error::EscrowNotFound)?;
+
+        escrow.ngo.require_auth();
+
+        if escrow.status != EscrowStatus::Disputed {
+            return Err(Error::NotDisputed);
+        }
+        if env.ledger().timestamp() >= escrow.dispute_start + escrow.policy.lock_duration {
+            return Err(Error::AppealWindowClosed);
+        }
+        if evidence_cids.is_empty() {
+            return Err(Error::EvidenceRequired);
+        }
+        let panel: Vec<Address> = env.storage().persistent()
+            .get(&DataKey::Arbitrators).unwrap_or(Vec::new(&env));
+        if panel.is_empty() {
+            return Err(Error::NoArbitrators);
+        }
+        if env.storage().persistent().has(&DataKey::Appeal(task_id.clone())) {
+            return Err(Error::AlreadyAppealed);
+        }
+
+        let now = env.ledger().timestamp();
+        let appeal = Appeal {
+            evidence_cids: evidence_cids.clone(),
+            filed_at: now,
+            restore_votes: 0,
+            reject_votes: 0,
+            arbitrators: Vec::new(&env),
+            panel,
+            deadline: now + ARBITRATION_WINDOW,
+            resolved_at: 0,
+        };
+        env.storage().persistent().set(&DataKey::Appeal(task_id.clone()), &appeal);
+
+        escrow.status = EscrowStatus::Appealed;
+        env.storage().persistent().set(&DataKey::Escrow(task_id.clone()), &escrow);
+
+        env.events().publish(
+            (symbol_short!("escrow"), symbol_short!("appeal")),
+            (task_id, evidence_cids.len()),
+        );
+
+        Ok(())
+    }
+
+    /// Arbitrator rules on an appeal before its deadline. `restore = true` sides
+    /// with the NGO. A majority of the appeal's panel settles the escrow:
+    /// restore → `Released` (NGO paid), otherwise → `Refunded` (donor paid).
+    /// Voter accuracy follows this outcome.
+    pub fn arbitrate(env: Env, task_id: String, arbitrator: Address, restore: bool) -> Result<(), Error> {
+        arbitrator.require_auth();
+
+        let mut escrow: EscrowData = env.storage().persistent()
+            .get(&DataKey::Escrow(task_id.clone()))
+            .ok_or(Error::EscrowNotFound)?;
+
+        if escrow.status != EscrowStatus::Appealed {
+            return Err(Error::NotAppealed);
+        }
+
+        let mut appeal: Appeal = env.storage().persistent()
+            .get(&DataKey::Appeal(task_id.clone()))
+            .ok_or(Error::NotAppealed)?;
+        if env.ledger().timestamp() >= appeal.deadline {
+            return Err(Error::AppealWindowClosed);
+        }
+        let panel = appeal.panel.clone();
+        if !panel.contains(&arbitrator) {
+            return Err(Error::NotArbitrator);
+        }
+        if appeal.arbitrators.contains(&arbitrator) {
+            return Err(Error::AlreadyVoted);
+        }
+
+        appeal.arbitrators.push_back(arbitrator.clone());
+        if restore {
+            appeal.restore_votes += 1;
+        } else {
+            appeal.reject_votes += 1;
+        }
+
+        env.events().publish(
+            (symbol_short!("escrow"), symbol_short!("arb_vote")),
+            (task_id.clone(), arbitrator, restore),
+        );
+
+        // Strict majority restores; once that is out of reach the refund is confirmed
+        let majority = panel.len() / 2 + 1;
+        let restored = appeal.restore_votes >= majority;
+        let rejected = appeal.reject_votes > panel.len() - majority;
+
+        if restored || rejected {
+            appeal.resolved_at = env.ledger().timestamp();
+        }
+        env.storage().persistent().set(&DataKey::Appeal(task_id.clone()), &appeal);
+
+        if restored {
+            Self::settle_appeal_release(&env, &mut escrow);
+        } else if rejected {
+            Self::settle_refund(&env, &mut escrow, true);
+        }
+
+        if restored || rejected {
+            env.events().publish(
+                (symbol_short!("escrow"), symbol_short!("arb_done")),
+                (task_id, restored),
+            );
+        }
+
+        Ok(())
+    }
+
+    // ── DEADLINE SETTLEMENT ─────────────────────────────────────
+
+    /// Mark an escrow `Expired` when its deadline passed without proof, or when its
+    /// vote round's window (or reveal phase) closed without reaching either the
+    /// release or the dispute quorum, so the final tally can never settle it.
+    /// Callable by anyone. For milestone escrows the current milestone applies, and
+    /// it and all later milestones are frozen for refund. Expired escrows refund
+    /// immediately.
+    pub fn settle_expired(env: Env, task_id: String) -> Result<(), Error> {
+        let mut escrow: EscrowData = env.storage().persistent()
+            .get(&DataKey::Escrow(task_id.clone()))
+            .ok_or(Error::EscrowNotFound)?;
+
+        if escrow.status != EscrowStatus::Locked {
+            return Err(Error::NotLocked);
+        }
+
+        let now = env.ledger().timestamp();
+        match escrow.kind {
+            EscrowKind::Standard | EscrowKind::Pooled => {
+                if !escrow.proof_cid.is_empty() {
+                    Self::require_undecided(
+                        &env, &escrow,
+                        escrow.real_votes + escrow.scam_votes, escrow.real_weight, escrow.scam_weight,
+                    )?;
+                } else if now <= escrow.deadline {
+                    return Err(Error::DeadlineNotPassed);
+                }
+            }
+            EscrowKind::Milestone => {
+                let mut current: Option<u32> = None;
+                for i in 0..escrow.milestones.len() {
+                    if escrow.milestones.get(i).unwrap().status == MilestoneStatus::Pending {
+                        current = Some(i);
+                        break;
+                    }
+                }
+                let current = current.ok_or(Error::NoPendingMilestone)?;
+                let m = escrow.milestones.get(current).unwrap();
+                if !m.proof_cid.is_empty() {
+                    Self::require_undecided(
+                        &env, &escrow, m.real_votes + m.scam_votes, m.real_weight, m.scam_weight,
+                    )?;
+                } else if now <= m.deadline {
+                    return Err(Error::DeadlineNotPassed);
+                }
+                for i in current..escrow.milestones.len() {
+                    let mut rest = escrow.milestones.get(i).unwrap();
+                    rest.status = MilestoneStatus::Frozen;
+                    escrow.milestones.set(i, rest);
+                }
+            }
+        }
+
+        escrow.status = EscrowStatus::Expired;
+        env.storage().persistent().set(&DataKey::Escrow(task_id.clone()), &escrow);
+
+        env.events().publish(
+            (symbol_short!("escrow"), symbol_short!("expired")),
+            (task_id, escrow.deadline),
+        );
+
+        Ok(())
+    }
+
+    // ── MILESTONES ──────────────────────────────────────────────
+
+    /// NGO submits proof for the current (first pending) milestone. The first
+    /// submission opens the milestone's vote round; later ones only replace the CID,
+    /// and only until the first vote is cast so no vote counts toward a proof it
+    /// never saw.
+    pub fn submit_milestone_proof(env: Env, task_id: String, milestone: u32, proof_cid: String) -> Result<(), Error> {
+        let mut escrow: EscrowData = env.storage().persistent()
+            .get(&DataKey::Escrow(task_id.clone()))
+            .ok_or(Error::EscrowNotFound)?;
+
+        escrow.ngo.require_auth();
+
+        let mut m = Self::current_milestone(&escrow, milestone)?;
+        if m.real_votes + m.scam_votes > 0 {
+            return Err(Error::VotingStarted);
+        }
+        if m.vote_start == 0 {
+            m.vote_start = env.ledger().timestamp();
+        }
+        m.proof_cid = proof_cid;
+        escrow.milestones.set(milestone, m);
+        env.storage().persistent().set(&DataKey::Escrow(task_id.clone()), &escrow);
+
+        env.events().publish(
+            (symbol_short!("escrow"), symbol_short!("ms_proof")),
+            (task_id, milestone),
+        );
+
+        Ok(())
+    }
+
+    /// Community member votes on the current milestone. Each milestone is its own round.
+    pub fn vote_milestone(env: Env, task_id: String, milestone: u32, voter: Address, is_scam: bool) -> Result<(), Error> {
+        voter.require_auth();
+
+        let mut escrow: EscrowData = env.storage().persistent()
+            .get(&DataKey::Escrow(task_id.clone()))
+            .ok_or(Error::EscrowNotFound)?;
+
+        Self::check_voter(&env, &escrow, &voter)?;
+        let mut m = Self::current_milestone(&escrow, milestone)?;
+        Self::require_voting_open(&env, &escrow.policy, m.vote_start)?;
+
+        let weight = Self::vote_weight(&env, &voter);
+        Self::append_vote(&env, &DataKey::MilestoneVotes(task_id.clone(), milestone), &voter, is_scam, 0, weight)?;
+
+        if is_scam {
+            m.scam_votes += 1;
+            m.scam_weight += weight;
+        } else {
+            m.real_votes += 1;
+            m.real_weight += weight;
+        }
+        escrow.milestones.set(milestone, m);
+        env.storage().persistent().set(&DataKey::Escrow(task_id.clone()), &escrow);
+
+        env.events().publish(
+            (symbol_short!("escrow"), symbol_short!("ms_vote")),
+            (task_id, milestone, voter, is_scam),
+        );
+
+        Ok(())
+    }
+
+    /// Release the current milestone's slice to the NGO. Callable by anyone
+    /// once the milestone's vote thresholds are met.
+    /// The escrow becomes `Released` once every milestone is paid.
+    pub fn release_milestone(env: Env, task_id: String, milestone: u32) -> Result<(), Error> {
+        let mut escrow: EscrowData = env.storage().persistent()
+            .get(&DataKey::Escrow(task_id.clone()))
+            .ok_or(Error::EscrowNotFound)?;
+
+        let mut m = Self::current_milestone(&escrow, milestone)?;
+        Self::require_window_closed(&env, &escrow.policy, m.vote_start)?;
+        Self::require_release_quorum(&escrow.policy, m.real_votes + m.scam_votes, m.real_weight, m.scam_weight)?;
+
+        let now = env.ledger().timestamp();
+        let amount = m.amount;
+        m.status = MilestoneStatus::Released;
+        m.released_at = now;
+        escrow.milestones.set(milestone, m);
+        escrow.released_amount += amount;
+
+        if escrow.released_amount == escrow.locked_amount {
+            escrow.status = EscrowStatus::Released;
+            escrow.released_at = now;
+        }
+        env.storage().persistent().set(&DataKey::Escrow(task_id.clone()), &escrow);
+
+        let fee = Self::take_fee(&env, &escrow, amount, false);
+        Self::record_release(&env, &escrow.ngo, amount, fee, false);
+        Self::reward_voters(&env, &escrow, &DataKey::MilestoneVotes(task_id.clone(), milestone), false);
+        Self::pay_out(&env, &escrow.token, &escrow.ngo, amount - fee);
+        if escrow.status == EscrowStatus::Released {
+            Self::issue_settlement_receipts(&env, &escrow, 0);
+        }
+
+        env.events().publish(
+            (symbol_short!("escrow"), symbol_short!("ms_rel")),
+            (task_id, milestone, escrow.ngo, amount),
+        );
+
+        Ok(())
+    }
+
+    /// Dispute the current milestone. It and all later milestones are frozen;
+    /// already-released milestones are unaffected. Starts the dispute lock.
+    /// Callable by anyone once the milestone's vote thresholds are met.
+    pub fn dispute_milestone(env: Env, task_id: String, milestone: u32) -> Result<(), Error> {
+        let mut escrow: EscrowData = env.storage().persistent()
+            .get(&DataKey::Escrow(task_id.clone()))
+            .ok_or(Error::EscrowNotFound)?;
+
+        let m = Self::current_milestone(&escrow, milestone)?;
+        Self::require_window_closed(&env, &escrow.policy, m.vote_start)?;
+        Self::require_dispute_quorum(&escrow.policy, m.real_votes + m.scam_votes, m.real_weight, m.scam_weight)?;
+
+        for i in milestone..escrow.milestones.len() {
+            let mut rest = escrow.milestones.get(i).unwrap();
+            rest.status = MilestoneStatus::Frozen;
+            escrow.milestones.set(i, rest);
+        }
+
+        escrow.status = EscrowStatus::Disputed;
+        escrow.dispute_start = env.ledger().timestamp();
+        env.storage().persistent().set(&DataKey::Escrow(task_id.clone()), &escrow);
+
+        let mut rep = Self::load_reputation(&env, &escrow.ngo);
+        rep.disputed_count += 1;
+        Self::save_reputation(&env, &escrow.ngo, rep);
+
+        env.events().publish(
+            (symbol_short!("escrow"), symbol_short!("ms_disp")),
+            (task_id, milestone, m.scam_votes, m.real_votes),
+        );
+
+        Ok(())
+    }
+
+    // ── QUERIES ─────────────────────────────────────────────────
+
+    /// Get escrow details by task ID.
+    pub fn get_escrow(env: Env, task_id: String) -> Result<EscrowData, Error> {
+        env.storage().persistent()
+            .get(&DataKey::Escrow(task_id))
+            .ok_or(Error::EscrowNotFound)
+    }
+
+    /// Get all escrows for an NGO. Unbounded; prefer `get_escrow_ids_page`.
+    pub fn get_ngo_escrows(env: Env, ngo: Address) -> Vec<String> {
+        let scope = IndexScope::Ngo(ngo);
+        let len = Self::index_len(&env, &scope);
+        Self::index_range(&env, &scope, 0, len)
+    }
+
+    /// Get all escrows for a donor. Unbounded; prefer `get_escrow_ids_page`.
+    pub fn get_donor_escrows(env: Env, donor: Address) -> Vec<String> {
+        let scope = IndexScope::Donor(donor);
+        let len = Self::index_len(&env, &scope);
+        Self::index_range(&env, &scope, 0, len)
+    }
+
+    /// Number of escrows in an index.
+    pub fn get_escrow_count(env: Env, scope: IndexScope) -> u32 {
+        Self::index_len(&env, &scope)
+    }
+
+    /// Up to `limit` task IDs (capped at MAX_PAGE_SIZE) starting at index position `cursor`.
+    pub fn get_escrow_ids_page(env: Env, scope: IndexScope, cursor: u32, limit: u32) -> Vec<String> {
+        Self::index_range(&env, &scope, cursor, limit.min(MAX_PAGE_SIZE))
+    }
+
+    /// Full escrow records for index positions `cursor..cursor + limit` (limit capped
+    /// at MAX_PAGE_SIZE), keeping only those in `status` when given. A filtered page
+    /// may hold fewer than `limit` records; keep going from `next_cursor`.
+    pub fn get_escrows_page(
+        env: Env,
+        scope: IndexScope,
+        cursor: u32,
+        limit: u32,
+        status: Option<EscrowStatus>,
+    ) -> EscrowPage {
+        let total = Self::index_len(&env, &scope);
+        let ids = Self::index_range(&env, &scope, cursor, limit.min(MAX_PAGE_SIZE));
+
+        let mut escrows: Vec<EscrowData> = Vec::new(&env);
+        for i in 0..ids.len() {
+            if let Some(escrow) = env.storage().persistent()
+                .get::<DataKey, EscrowData>(&DataKey::Escrow(ids.get(i).unwrap()))
+            {
+                if status.is_none() || status == Some(escrow.status) {
+                    escrows.push_back(escrow);
+                }
+            }
+        }
+
+        EscrowPage {
+            escrows,
+            next_cursor: cursor.min(total) + ids.len(),
+            total,
+        }
+    }
+
+    /// Get votes for a specific escrow.
+    pub fn get_votes(env: Env, task_id: String) -> Vec<VoteRecord> {
+        env.storage().persistent()
+            .get(&DataKey::Votes(task_id))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Get votes for one milestone of a milestone escrow.
+    pub fn get_milestone_votes(env: Env, task_id: String, milestone: u32) -> Vec<VoteRecord> {
+        env.storage().persistent()
+            .get(&DataKey::MilestoneVotes(task_id, milestone))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Get vote commitments (revealed and unrevealed) for a commit–reveal escrow.
+    pub fn get_commits(env: Env, task_id: String) -> Vec<VoteCommit> {
+        env.storage().persistent()
+            .get(&DataKey::Commits(task_id))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Get the NGO appeal for an escrow, if any.
+    pub fn get_appeal(env: Env, task_id: String) -> Option<Appeal> {
+        env.storage().persistent().get(&DataKey::Appeal(task_id))
+    }
+
+    /// Get the arbitrator panel.
+    pub fn get_arbitrators(env: Env) -> Vec<Address> {
+        env.storage().persistent()
+            .get(&DataKey::Arbitrators)
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Get voter accuracy stats.
+    pub fn get_voter_stats(env: Env, voter: Address) -> VoterAccuracy {
+        env.storage().persistent()
+            .get(&DataKey::VoterStats(voter))
+            .unwrap_or(VoterAccuracy {
+                correct_votes: 0,
+                total_votes: 0,
+                rewards_earned: 0,
+                net_bond: 0,
+            })
+    }
+
+    /// Get every proof submitted for an escrow, oldest first.
+    pub fn get_proof_history(env: Env, task_id: String) -> Vec<ProofSubmission> {
+        env.storage().persistent()
+            .get(&DataKey::ProofHistory(task_id))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Get the voting phase of an escrow (its current milestone, for milestone escrows).
+    pub fn get_voting_phase(env: Env, task_id: String) -> Result<VotingPhase, Error> {
+        let escrow: EscrowData = env.storage().persistent()
+            .get(&DataKey::Escrow(task_id))
+            .ok_or(Error::EscrowNotFound)?;
+
+        if escrow.status != EscrowStatus::Locked {
+            return Ok(VotingPhase::Closed);
+        }
+        let windows = Self::voting_windows(&escrow);
+        let now = env.ledger().timestamp();
+        let phase = if windows.vote_start == 0 {
+            VotingPhase::AwaitingProof
+        } else if escrow.commit_window > 0 && now < windows.commit_end {
+            VotingPhase::Commit
+        } else if escrow.commit_window > 0 && now < windows.reveal_end {
+            VotingPhase::Reveal
+        } else if escrow.commit_window == 0 && (windows.vote_end == 0 || now < windows.vote_end) {
+            VotingPhase::Open
+        } else {
+            VotingPhase::Final
+        };
+        Ok(phase)
+    }
+
+    /// Get the voting window boundaries of an escrow's current vote round.
+    pub fn get_voting_windows(env: Env, task_id: String) -> Result<VotingWindows, Error> {
+        let escrow: EscrowData = env.storage().persistent()
+            .get(&DataKey::Escrow(task_id))
+            .ok_or(Error::EscrowNotFound)?;
+        Ok(Self::voting_windows(&escrow))
+    }
+
+    /// Get the archived votes on an earlier proof version (`NewRound` mode).
+    pub fn get_round_votes(env: Env, task_id: String, proof_version: u32) -> Vec<VoteRecord> {
+        env.storage().persistent()
+            .get(&DataKey::RoundVotes(task_id, proof_version))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Get per-donor contributions to a pooled escrow.
+    pub fn get_contributions(env: Env, task_id: String) -> Vec<Contribution> {
+        env.storage().persistent()
+            .get(&DataKey::Contributions(task_id))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Get a donation receipt by id.
+    pub fn get_receipt(env: Env, id: u32) -> Option<Receipt> {
+        env.storage().persistent().get(&DataKey::Receipt(id))
+    }
+
+    /// Number of receipts issued to a donor.
+    pub fn get_donor_receipt_count(env: Env, donor: Address) -> u32 {
+        env.storage().persistent()
+            .get(&DataKey::DonorReceiptCount(donor)).unwrap_or(0)
+    }
+
+    /// Up to `limit` of a donor's receipts (capped at MAX_PAGE_SIZE), oldest
+    /// first, starting at their `start`-th receipt.
+    pub fn get_donor_receipts(env: Env, donor: Address, start: u32, limit: u32) -> Vec<Receipt> {
+        let end = start.saturating_add(limit.min(MAX_PAGE_SIZE))
+            .min(Self::get_donor_receipt_count(env.clone(), donor.clone()));
+        let mut receipts = Vec::new(&env);
+        let mut pos = start;
+        while pos < end {
+            let bucket: Vec<u32> = env.storage().persistent()
+                .get(&DataKey::DonorReceiptBucket(donor.clone(), pos / INDEX_BUCKET_SIZE))
+                .unwrap_or(Vec::new(&env));
+            let mut i = pos % INDEX_BUCKET_SIZE;
+            if i >= bucket.len() {
+                break;
+            }
+            while i < bucket.len() && pos < end {
+                if let Some(r) = env.storage().persistent().get::<_, Receipt>(&DataKey::Receipt(bucket.get(i).unwrap())) {
+                    receipts.push_back(r);
+                }
+                i += 1;
+                pos += 1;
+            }
+        }
+        receipts
+    }
+
+    /// Check whether `donor` holds a receipt for the escrow `task_id`.
+    pub fn verify_receipt(env: Env, donor: Address, task_id: String) -> bool {
+        env.storage().persistent().has(&DataKey::DonorHasReceipt(donor, task_id))
+    }
+
+    /// Get the vote bond settings for an escrow, if bonded voting is on.
+    pub fn get_vote_bond(env: Env, task_id: String) -> Option<VoteBond> {
+        env.storage().persistent().get(&DataKey::VoteBond(task_id))
+    }
+
+    /// Get who `delegator` has delegated to for `ngo` (`None` = global delegation).
+    pub fn get_delegate(env: Env, delegator: Address, ngo: Option<Address>) -> Option<Address> {
+        env.storage().persistent().get(&DataKey::Delegation(delegator, ngo))
+    }
+
+    /// Get the delegate `delegator` has offered to delegate to, awaiting acceptance.
+    pub fn get_pending_delegate(env: Env, delegator: Address, ngo: Option<Address>) -> Option<Address> {
+        env.storage().persistent().get(&DataKey::PendingDelegation(delegator, ngo))
+    }
+
+    /// Get the addresses delegating to `delegate` for `ngo` (`None` = global).
+    pub fn get_delegators(env: Env, delegate: Address, ngo: Option<Address>) -> Vec<Address> {
+        env.storage().persistent()
+            .get(&DataKey::Delegators(delegate, ngo))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Get the voter eligibility requirements, if set.
+    pub fn get_voter_gate(env: Env) -> Option<VoterGate> {
+        env.storage().persistent().get(&DataKey::VoterGate)
+    }
+
+    /// Get the vote weighting mode.
+    pub fn get_vote_weighting(env: Env) -> VoteWeighting {
+        env.storage().persistent()
+            .get(&DataKey::VoteWeighting).unwrap_or(VoteWeighting::Flat)
+    }
+
+    /// Get the weight a vote by `voter` would carry right now.
+    pub fn get_vote_weight(env: Env, voter: Address) -> u32 {
+        Self::vote_weight(&env, &voter)
+    }
+
+    /// Get the current global escrow policy applied to new escrows.
+    pub fn get_policy(env: Env) -> EscrowPolicy {
+        Self::global_policy(&env)
+    }
+
+    /// Get the voter reward configuration, if set.
+    pub fn get_reward_config(env: Env) -> Option<RewardConfig> {
+        env.storage().persistent().get(&DataKey::RewardConfig)
+    }
+
+    /// Get the Reiatsu left in the reward pool.
+    pub fn get_reward_pool(env: Env) -> i128 {
+        env.storage().persistent().get(&DataKey::RewardPool).unwrap_or(0)
+    }
+
+    /// Get an NGO's escrow track record and trust score.
+    pub fn get_ngo_reputation(env: Env, ngo: Address) -> NgoReputation {
+        Self::load_reputation(&env, &ngo)
+    }
+
+    /// Whether `token` can be used for new escrows.
+    pub fn is_token_accepted(env: Env, token: Address) -> bool {
+        env.storage().persistent().has(&DataKey::AcceptedToken(token))
+    }
+
+    /// Get the minimum-reputation rule for large escrows, if set.
+    pub fn get_reputation_gate(env: Env) -> Option<ReputationGate> {
+        env.storage().persistent().get(&DataKey::ReputationGate)
+    }
+
+    /// Get the platform fee configuration, if set.
+    pub fn get_fee_config(env: Env) -> Option<FeeConfig> {
+        env.storage().persistent().get(&DataKey::FeeConfig)
+    }
+
+    /// Get global platform stats in the accepted tokens' unit: (locked, released, refunded, fees).
+    pub fn get_platform_stats(env: Env) -> (i128, i128, i128, i128) {
+        let locked: i128 = env.storage().persistent()
+            .get(&DataKey::TotalLocked).unwrap_or(0);
+        let released: i128 = env.storage().persistent()
+            .get(&DataKey::TotalReleased).unwrap_or(0);
+        let refunded: i128 = env.storage().persistent()
+            .get(&DataKey::TotalRefunded).unwrap_or(0);
+        let fees: i128 = env.storage().persistent()
+            .get(&DataKey::TotalFees).unwrap_or(0);
+        (locked, released, refunded, fees)
+    }
+
+    /// Get all escrow task IDs. Unbounded; prefer `get_escrow_ids_page`.
+    pub fn get_all_escrow_ids(env: Env) -> Vec<String> {
+        let len = Self::index_len(&env, &IndexScope::All);
+        Self::index_range(&env, &IndexScope::All, 0, len)
+    }
+
+    // ── INTERNAL HELPERS ────────────────────────────────────────
+
+    /// Persist a new escrow, index it, update stats and take custody of the locked funds.
+    fn open_escrow(env: &Env, escrow: &EscrowData) {
+        let task_id = escrow.task_id.clone();
+        env.storage().persistent().set(&DataKey::Escrow(task_id.clone()), escrow);
+
+        // Initialize empty vote list
+        let empty_votes: Vec<VoteRecord> = Vec::new(env);
+        env.storage().persistent().set(&DataKey::Votes(task_id.clone()), &empty_votes);
+
+        // Update indices
+        Self::index_push(env, IndexScope::All, &task_id);
+        Self::index_push(env, IndexScope::Ngo(escrow.ngo.clone()), &task_id);
+        Self::index_push(env, IndexScope::Donor(escrow.donor.clone()), &task_id);
+
+        // Update global locked total
+        let total_locked: i128 = env.storage().persistent()
+            .get(&DataKey::TotalLocked).unwrap_or(0);
+        env.storage().persistent().set(&DataKey::TotalLocked, &(total_locked + escrow.locked_amount));
+
+        // Take custody of the locked funds
+        token::TokenClient::new(env, &escrow.token)
+            .transfer(&escrow.donor, env.current_contract_address(), &escrow.locked_amount);
+        Self::issue_receipt(env, escrow, &escrow.donor, ReceiptKind::Donation, escrow.locked_amount, 0);
+
+        env.events().publish(
+            (symbol_short!("escrow"), symbol_short!("created")),
+            (task_id, escrow.donor.clone(), escrow.ngo.clone(), escrow.locked_amount),
+        );
+    }
+
+    /// Common eligibility checks for direct and committed votes.
+    fn check_voter(env: &Env, escrow: &EscrowData, voter: &Address) -> Result<(), Error> {
+        Self::check_party(env, escrow, voter)?;
+        Self::check_reaper(env, voter)
+    }
+
+    /// The escrow must be open and `voter` must not be its donor, NGO or a contributor.
+    fn check_party(env: &Env, escrow: &EscrowData, voter: &Address) -> Result<(), Error> {
+        if escrow.status != EscrowStatus::Locked {
+            return Err(Error::NotLocked);
+        }
+
+        // Prevent donor/NGO from voting on their own escrow
+        if *voter == escrow.donor || *voter == escrow.ngo {
+            return Err(Error::CannotVoteOwnEscrow);
+        }
+        if escrow.kind == EscrowKind::Pooled {
+            let contributions: Vec<Contribution> = env.storage().persistent()
+                .get(&DataKey::Contributions(escrow.task_id.clone())).unwrap_or(Vec::new(env));
+            for i in 0..contributions.len() {
+                if contributions.get(i).unwrap().donor == *voter {
+                    return Err(Error::CannotVoteOwnEscrow);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Enforce the voter gate (if configured) via the reaper registry and badge contract.
+    fn check_reaper(env: &Env, voter: &Address) -> Result<(), Error> {
+        let gate: VoterGate = match env.storage().persistent().get(&DataKey::VoterGate) {
+            Some(g) => g,
+            None => return Ok(()),
+        };
+
+        let reaper: Option<SoulReaper> = env.invoke_contract(
+            &gate.registry,
+            &Symbol::new(env, "get_reaper"),
+            (voter.clone(),).into_val(env),
+        );
+        let reaper = reaper.ok_or(Error::NotRegisteredReaper)?;
+        if reaper.status != ReaperStatus::Active {
+            return Err(Error::ReaperNotActive);
+        }
+
+        if let Some(badges) = gate.badge_contract {
+            let count: u32 = env.invoke_contract(
+                &badges,
+                &Symbol::new(env, "badge_count"),
+                (voter.clone(),).into_val(env),
+            );
+            if count < gate.min_badges {
+                return Err(Error::NotEnoughBadges);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Close the vote round on the current proof before a new one is submitted:
+    /// bonds on its votes are returned in full, the votes stop counting toward
+    /// voter stats, and they are archived (`NewRound`) or dropped (`ResetTally`).
+    fn close_proof_round(env: &Env, escrow: &mut EscrowData) -> Result<(), Error> {
+        let task_id = escrow.task_id.clone();
+        let commits: Vec<VoteCommit> = env.storage().persistent()
+            .get(&DataKey::Commits(task_id.clone())).unwrap_or(Vec::new(env));
+        if !commits.is_empty() {
+            return Err(Error::VotingStarted);
+        }
+
+        Self::settle_bonds(env, &task_id, None);
+
+        let votes: Vec<VoteRecord> = env.storage().persistent()
+            .get(&DataKey::Votes(task_id.clone())).unwrap_or(Vec::new(env));
+        for i in 0..votes.len() {
+            let voter = votes.get(i).unwrap().voter;
+            if let Some(mut stats) = env.storage().persistent()
+                .get::<DataKey, VoterAccuracy>(&DataKey::VoterStats(voter.clone()))
+            {
+                stats.total_votes = stats.total_votes.saturating_sub(1);
+                env.storage().persistent().set(&DataKey::VoterStats(voter), &stats);
+            }
+        }
+        if escrow.resubmit_mode == ResubmitMode::NewRound {
+            env.storage().persistent()
+                .set(&DataKey::RoundVotes(task_id.clone(), escrow.proof_version), &votes);
+        }
+        env.storage().persistent().set(&DataKey::Votes(task_id), &Vec::<VoteRecord>::new(env));
+
+        escrow.real_votes = 0;
+        escrow.scam_votes = 0;
+        escrow.real_weight = 0;
+        escrow.scam_weight = 0;
+        Ok(())
+    }
+
+    /// Record a counted vote: append the record, update the tally and voter stats.
+    /// Delegated weight is added here: the voter also counts for everyone who
+    /// delegated to them and has not voted (directly or through someone else).
+    /// Each delegator carries their own weight, fixed when the vote is recorded.
+    /// On bonded escrows the voter locks one extra bond per delegator counted.
+    fn record_vote(env: &Env, task_id: &String, escrow: &mut EscrowData, voter: &Address, is_scam: bool) -> Result<(), Error> {
+        let votes_key = DataKey::Votes(task_id.clone());
+        let own_weight = Self::vote_weight(env, voter);
+        Self::append_vote(env, &votes_key, voter, is_scam, escrow.proof_version, own_weight)?;
+
+        // A direct vote overrides any delegate who already counted this voter;
+        // the delegate gets back the bond that covered it
+        let bond: Option<VoteBond> = env.storage().persistent().get(&DataKey::VoteBond(task_id.clone()));
+        let mut votes: Vec<VoteRecord> = env.storage().persistent()
+            .get(&votes_key).unwrap_or(Vec::new(env));
+        for i in 0..votes.len() {
+            let mut record = votes.get(i).unwrap();
+            if let Some(pos) = record.delegators.first_index_of(voter) {
+                let withdrawn = record.delegator_weights.get(pos).unwrap();
+                record.delegators.remove(pos);
+                record.delegator_weights.remove(pos);
+                record.weight -= withdrawn;
+                if let Some(bond) = &bond {
+                    record.bonded -= bond.amount;
+                    Self::return_bond(env, bond, &record.voter, bond.amount, bond.amount);
+                }
+                if record.is_scam {
+                    escrow.scam_votes -= 1;
+                    escrow.scam_weight -= withdrawn;
+                } else {
+                    escrow.real_votes -= 1;
+                    escrow.real_weight -= withdrawn;
+                }
+                votes.set(i, record);
+            }
+        }
+
+        let delegators = Self::collect_delegators(env, escrow, &votes, voter);
+        let mut delegator_weights: Vec<u32> = Vec::new(env);
+        let mut weight = own_weight;
+        for i in 0..delegators.len() {
+            let w = Self::vote_weight(env, &delegators.get(i).unwrap());
+            delegator_weights.push_back(w);
+            weight += w;
+        }
+        let count = 1 + delegators.len();
+        Self::lock_bond(env, task_id, voter, delegators.len());
+        let last = votes.len() - 1;
+        let mut record = votes.get(last).unwrap();
+        record.delegators = delegators;
+        record.delegator_weights = delegator_weights;
+        record.weight = weight;
+        record.bonded = bond.map(|b| b.amount * count as i128).unwrap_or(0);
+        votes.set(last, record);
+        env.storage().persistent().set(&votes_key, &votes);
+
+        // Update escrow vote counts and weighted tally
+        if is_scam {
+            escrow.scam_votes += count;
+            escrow.scam_weight += weight;
+        } else {
+            escrow.real_votes += count;
+            escrow.real_weight += weight;
+        }
+        env.storage().persistent().set(&DataKey::Escrow(task_id.clone()), escrow);
+
+        env.events().publish(
+            (symbol_short!("escrow"), symbol_short!("vote")),
+            (task_id.clone(), voter.clone(), is_scam, count, weight),
+        );
+
+        Ok(())
+    }
+
+    /// Append a vote to a round's vote list (rejecting double votes) and bump voter stats.
+    fn append_vote(env: &Env, votes_key: &DataKey, voter: &Address, is_scam: bool, proof_version: u32, weight: u32) -> Result<(), Error> {
+        // Check for double voting
+        let mut votes: Vec<VoteRecord> = env.storage().persistent()
+            .get(votes_key).unwrap_or(Vec::new(env));
+
+        for i in 0..votes.len() {
+            if votes.get(i).unwrap().voter == *voter {
+                return Err(Error::AlreadyVoted);
+            }
+        }
+
+        // Record vote
+        let vote = VoteRecord {
+            voter: voter.clone(),
+            is_scam,
+            timestamp: env.ledger().timestamp(),
+            proof_version,
+            delegators: Vec::new(env),
+            weight,
+            delegator_weights: Vec::new(env),
+            bonded: 0,
+        };
+        votes.push_back(vote);
+        env.storage().persistent().set(votes_key, &votes);
+
+        // Update voter stats
+        let mut stats: VoterAccuracy = env.storage().persistent()
+            .get(&DataKey::VoterStats(voter.clone()))
+            .unwrap_or(VoterAccuracy {
+                correct_votes: 0,
+                total_votes: 0,
+                rewards_earned: 0,
+                net_bond: 0,
+            });
+        stats.total_votes += 1;
+        env.storage().persistent().set(&DataKey::VoterStats(voter.clone()), &stats);
+
+        Ok(())
+    }
+
+    /// Weight of a vote cast now by `voter` under the current weighting mode.
+    /// Must be computed before the vote bumps the voter's stats.
+    fn vote_weight(env: &Env, voter: &Address) -> u32 {
+        let mode: VoteWeighting = env.storage().persistent()
+            .get(&DataKey::VoteWeighting).unwrap_or(VoteWeighting::Flat);
+        match mode {
+            VoteWeighting::Flat => WEIGHT_UNIT,
+            VoteWeighting::Accuracy => Self::accuracy_weight(env, voter),
+            VoteWeighting::Badges => Self::badge_weight(env, voter),
+            VoteWeighting::Both => {
+                Self::accuracy_weight(env, voter) * Self::badge_weight(env, voter) / WEIGHT_UNIT
+            }
+        }
+    }
+
+    /// Share of the voter's votes that were correct, out of WEIGHT_UNIT.
+    /// Newcomers get at least ACCURACY_FLOOR; nobody drops to zero.
+    fn accuracy_weight(env: &Env, voter: &Address) -> u32 {
+        let stats: Option<VoterAccuracy> = env.storage().persistent()
+            .get(&DataKey::VoterStats(voter.clone()));
+        let (correct, total) = stats.map(|s| (s.correct_votes, s.total_votes)).unwrap_or((0, 0));
+
+        let ratio = (correct * WEIGHT_UNIT).checked_div(total).unwrap_or(0);
+        if total < NEWCOMER_VOTES {
+            ratio.max(ACCURACY_FLOOR)
+        } else {
+            ratio.max(1)
+        }
+    }
+
+    /// Badge tier multiplier (out of WEIGHT_UNIT), using soul-badge's tier bands:
+    /// none 1×, Bronze 1.25×, Silver 1.5×, Gold 2×, Bankai 3×.
+    fn badge_weight(env: &Env, voter: &Address) -> u32 {
+        let gate: Option<VoterGate> = env.storage().persistent().get(&DataKey::VoterGate);
+        let badges = match gate.and_then(|g| g.badge_contract) {
+            Some(b) => b,
+            None => return WEIGHT_UNIT,
+        };
+        let count: u32 = env.invoke_contract(
+            &badges,
+            &Symbol::new(env, "badge_count"),
+            (voter.clone(),).into_val(env),
+        );
+        match count {
+            0 => WEIGHT_UNIT,
+            1..=5 => 125,
+            6..=15 => 150,
+            16..=30 => 200,
+            _ => 300,
+        }
+    }
+
+    /// Reject tokens the admin has not accepted, so every escrowed amount is in one unit.
+    fn require_accepted_token(env: &Env, token: &Address) -> Result<(), Error> {
+        if !env.storage().persistent().has(&DataKey::AcceptedToken(token.clone())) {
+            return Err(Error::InvalidConfig);
+        }
+        Ok(())
+    }
+
+    fn require_admin(env: &Env) -> Result<(), Error> {
         let admin: Address = env.storage().persistent()
-            .get(&DataKey::Admin).expect("Not initialized");
+            .get(&DataKey::Admin).ok_or(Error::NotInitialized)?;
         admin.require_auth();
+        Ok(())
+    }
 
-        let mut escrow: EscrowData = env.storage().persistent()
-            .get(&DataKey::Escrow(task_id.clone()))
-            .expect("Escrow not found");
+    fn global_policy(env: &Env) -> EscrowPolicy {
+        let config: Option<RewardConfig> = env.storage().persistent().get(&DataKey::RewardConfig);
+        EscrowPolicy {
+            dispute_threshold: env.storage().persistent()
+                .get(&DataKey::DisputeThreshold).unwrap_or(DEFAULT_DISPUTE_THRESHOLD),
+            lock_duration: env.storage().persistent()
+                .get(&DataKey::LockDuration).unwrap_or(DEFAULT_LOCK_DURATION),
+            min_votes: env.storage().persistent()
+                .get(&DataKey::MinVotes).unwrap_or(DEFAULT_MIN_VOTES),
+            voter_reward: config.map(|c| c.amount).unwrap_or(0),
+            vote_window: env.storage().persistent()
+                .get(&DataKey::VoteWindow).unwrap_or(DEFAULT_VOTE_WINDOW),
+        }
+    }
+
+    /// The policy a new escrow is created under: the global policy, or a donor
+    /// override that is at least as strict on threshold, lock, quorum and window. The
+    /// voter reward is platform funded, so an override must keep the global value.
+    fn resolve_policy(env: &Env, requested: Option<EscrowPolicy>) -> Result<EscrowPolicy, Error> {
+        let global = Self::global_policy(env);
+        let policy = match requested {
+            Some(p) => p,
+            None => return Ok(global),
+        };
+        if policy.dispute_threshold > 100 || policy.voter_reward != global.voter_reward {
+            return Err(Error::InvalidConfig);
+        }
+        if policy.dispute_threshold < global.dispute_threshold
+            || policy.lock_duration < global.lock_duration
+            || policy.min_votes < global.min_votes
+            || policy.vote_window < global.vote_window
+        {
+            return Err(Error::PolicyTooLax);
+        }
+        Ok(policy)
+    }
+
+    fn publish_policy(env: &Env) {
+        env.events().publish(
+            (symbol_short!("escrow"), symbol_short!("policy")),
+            Self::global_policy(env),
+        );
+    }
+
+    fn load_reputation(env: &Env, ngo: &Address) -> NgoReputation {
+        env.storage().persistent()
+            .get(&DataKey::Reputation(ngo.clone()))
+            .unwrap_or(NgoReputation {
+                released_count: 0,
+                disputed_count: 0,
+                refunded_count: 0,
+                donor_released_count: 0,
+                total_released: 0,
+                total_refunded: 0,
+                total_donor_released: 0,
+                trust_score: NEUTRAL_TRUST_SCORE,
+            })
+    }
+
+    /// Recompute the trust score and persist the record.
+    fn save_reputation(env: &Env, ngo: &Address, mut rep: NgoReputation) {
+        let settled = rep.total_released + rep.total_refunded;
+        rep.trust_score = if settled > 0 {
+            (rep.total_released * 100 / settled) as u32
+        } else {
+            NEUTRAL_TRUST_SCORE
+        };
+        env.storage().persistent().set(&DataKey::Reputation(ngo.clone()), &rep);
+    }
+
+    /// Enforce the reputation gate (if configured) for an escrow locking `amount`.
+    fn check_reputation(env: &Env, ngo: &Address, amount: i128) -> Result<(), Error> {
+        let gate: ReputationGate = match env.storage().persistent().get(&DataKey::ReputationGate) {
+            Some(g) => g,
+            None => return Ok(()),
+        };
+        if amount >= gate.min_amount && Self::load_reputation(env, ngo).trust_score < gate.min_score {
+            return Err(Error::ReputationTooLow);
+        }
+        Ok(())
+    }
+
+    fn index_len(env: &Env, scope: &IndexScope) -> u32 {
+        env.storage().persistent()
+            .get(&DataKey::IndexLen(scope.clone())).unwrap_or(0)
+    }
+
+    /// Append a task ID to the last bucket of an index, starting a new bucket
+    /// every INDEX_BUCKET_SIZE entries so no single entry grows without bound.
+    fn index_push(env: &Env, scope: IndexScope, task_id: &String) {
+        let len = Self::index_len(env, &scope);
+        let key = DataKey::IndexBucket(scope.clone(), len / INDEX_BUCKET_SIZE);
+        let mut bucket: Vec<String> = env.storage().persistent()
+            .get(&key).unwrap_or(Vec::new(env));
+        bucket.push_back(task_id.clone());
+        env.storage().persistent().set(&key, &bucket);
+        env.storage().persistent().set(&DataKey::IndexLen(scope), &(len + 1));
+    }
+
+    /// Read index positions `start..start + count`, touching only the buckets involved.
+    fn index_range(env: &Env, scope: &IndexScope, start: u32, count: u32) -> Vec<String> {
+        let end = start.saturating_add(count).min(Self::index_len(env, scope));
+        let mut ids: Vec<String> = Vec::new(env);
+        let mut pos = start;
+        while pos < end {
+            let bucket: Vec<String> = env.storage().persistent()
+                .get(&DataKey::IndexBucket(scope.clone(), pos / INDEX_BUCKET_SIZE))
+                .unwrap_or(Vec::new(env));
+            let mut i = pos % INDEX_BUCKET_SIZE;
+            if i >= bucket.len() {
+                break;
+            }
+            while i < bucket.len() && pos < end {
+                ids.push_back(bucket.get(i).unwrap());
+                i += 1;
+                pos += 1;
+            }
+        }
+        ids
+    }
+
+    /// Reject operations that only apply to single-round (Standard or Pooled) escrows.
+    fn require_standard(escrow: &EscrowData) -> Result<(), Error> {
+        if escrow.kind == EscrowKind::Milestone {
+            return Err(Error::WrongEscrowKind);
+        }
 
+        Ok(())
+    }
+
+    /// Return the requested milestone, which must be the current (first pending) one
+    /// of a locked milestone escrow.
+    fn current_milestone(escrow: &EscrowData, milestone: u32) -> Result<Milestone, Error> {
+        if escrow.kind != EscrowKind::Milestone {
+            return Err(Error::WrongEscrowKind);
+        }
         if escrow.status != EscrowStatus::Locked {
-            panic!("Escrow is not in Locked state");
+            return Err(Error::NotLocked);
+        }
+        for i in 0..escrow.milestones.len() {
+            let m = escrow.milestones.get(i).unwrap();
+            if m.status == MilestoneStatus::Pending {
+                if i != milestone {
+                    return Err(Error::NotCurrentMilestone);
+                }
+                return Ok(m);
+            }
+        }
+        Err(Error::NoPendingMilestone)
+    }
+
+    /// Release requires the minimum (raw) vote count and weighted `real` share
+    /// at or above the threshold.
+    fn require_release_quorum(policy: &EscrowPolicy, total_votes: u32, real_weight: u32, scam_weight: u32) -> Result<(), Error> {
+        let total_weight = real_weight as u64 + scam_weight as u64;
+        if total_votes < policy.min_votes || total_weight == 0 {
+            return Err(Error::NotEnoughVotes);
         }
 
-        let total_votes = escrow.real_votes + escrow.scam_votes;
-        if total_votes < MIN_VOTES_FOR_DECISION {
-            panic!("Not enough votes to dispute");
+        let real_pct = (real_weight as u64 * 100) / total_weight;
+        if real_pct < policy.dispute_threshold as u64 {
+            return Err(Error::NotEnoughRealVotes);
         }
 
-        let scam_pct = (escrow.scam_votes as u64 * 100) / total_votes as u64;
-        let threshold = env.storage().persistent()
-            .get(&DataKey::DisputeThreshold).unwrap_or(DEFAULT_DISPUTE_THRESHOLD);
+        Ok(())
+    }
 
-        if scam_pct < threshold as u64 {
-            panic!("Not enough scam votes to dispute");
+    /// Dispute requires the minimum (raw) vote count and weighted `scam` share
+    /// at or above the threshold.
+    fn require_dispute_quorum(policy: &EscrowPolicy, total_votes: u32, real_weight: u32, scam_weight: u32) -> Result<(), Error> {
+        let total_weight = real_weight as u64 + scam_weight as u64;
+        if total_votes < policy.min_votes || total_weight == 0 {
+            return Err(Error::NotEnoughVotes);
         }
 
-        escrow.status = EscrowStatus::Disputed;
-        escrow.dispute_start = env.ledger().timestamp();
-        env.storage().persistent().set(&DataKey::Escrow(task_id.clone()), &escrow);
+        let scam_pct = (scam_weight as u64 * 100) / total_weight;
+        if scam_pct < policy.dispute_threshold as u64 {
+            return Err(Error::NotEnoughScamVotes);
+        }
 
-        env.events().publish(
-            (symbol_short!("escrow"), symbol_short!("dispute")),
-            (task_id, escrow.scam_votes, escrow.real_votes),
-        );
+        Ok(())
     }
 
-    // ── REFUND ESCROW ───────────────────────────────────────────
+    /// Move `amount` from the global locked total to the released total.
+    /// `amount` leaves custody; `amount - fee` counts as released to the NGO.
+    /// Donor releases (`by_donor`) go to their own reputation counters.
+    fn record_release(env: &Env, ngo: &Address, amount: i128, fee: i128, by_donor: bool) {
+        let total_locked: i128 = env.storage().persistent()
+            .get(&DataKey::TotalLocked).unwrap_or(0);
+        env.storage().persistent().set(&DataKey::TotalLocked, &(total_locked - amount));
 
-    /// Refund locked funds to the donor after the 30-day dispute lock.
-    /// Requires: status == Disputed, lock period expired.
-    pub fn refund(env: Env, task_id: String) {
-        let admin: Address = env.storage().persistent()
-            .get(&DataKey::Admin).expect("Not initialized");
-        admin.require_auth();
+        let total_released: i128 = env.storage().persistent()
+            .get(&DataKey::TotalReleased).unwrap_or(0);
+        env.storage().persistent().set(&DataKey::TotalReleased, &(total_released + amount - fee));
 
-        let mut escrow: EscrowData = env.storage().persistent()
-            .get(&DataKey::Escrow(task_id.clone()))
-            .expect("Escrow not found");
+        let mut rep = Self::load_reputation(env, ngo);
+        if by_donor {
+            rep.donor_released_count += 1;
+            rep.total_donor_released += amount;
+        } else {
+            rep.released_count += 1;
+            rep.total_released += amount;
+        }
+        Self::save_reputation(env, ngo, rep);
+    }
 
-        if escrow.status != EscrowStatus::Disputed {
-            panic!("Escrow must be in Disputed state to refund");
+    /// Tallies are only final once the reveal phase (commit–reveal) or the
+    /// voting window (direct voting) is over.
+    fn require_tally_final(env: &Env, escrow: &EscrowData) -> Result<(), Error> {
+        if escrow.commit_window > 0 {
+            let reveal_end = escrow.vote_start + escrow.commit_window + escrow.reveal_window;
+            if env.ledger().timestamp() < reveal_end {
+                return Err(Error::WrongVotingPhase);
+            }
+            return Ok(());
         }
 
-        let now = env.ledger().timestamp();
-        let lock_end = escrow.dispute_start + escrow.dispute_lock_days;
-        if now < lock_end {
-            panic!("Lock period has not expired yet");
+        Self::require_window_closed(env, &escrow.policy, escrow.vote_start)
+    }
+
+    /// Direct votes need a proof (`vote_start` set) and an open voting window.
+    fn require_voting_open(env: &Env, policy: &EscrowPolicy, vote_start: u64) -> Result<(), Error> {
+        if vote_start == 0 {
+            return Err(Error::ProofRequired);
+        }
+        if policy.vote_window > 0 && env.ledger().timestamp() >= vote_start + policy.vote_window {
+            return Err(Error::WrongVotingPhase);
+        }
+
+        Ok(())
+    }
+
+    /// A proven round can only expire once its tally is final and meets neither
+    /// quorum. Open-ended rounds (no vote window) can still gather votes.
+    fn require_undecided(
+        env: &Env, escrow: &EscrowData, total_votes: u32, real_weight: u32, scam_weight: u32,
+    ) -> Result<(), Error> {
+        let windows = Self::voting_windows(escrow);
+        if windows.vote_end == 0 {
+            return Err(Error::ProofSubmitted);
+        }
+        if env.ledger().timestamp() < windows.vote_end {
+            return Err(Error::WrongVotingPhase);
+        }
+        if Self::require_release_quorum(&escrow.policy, total_votes, real_weight, scam_weight).is_ok()
+            || Self::require_dispute_quorum(&escrow.policy, total_votes, real_weight, scam_weight).is_ok()
+        {
+            return Err(Error::TallyDecided);
+        }
+
+        Ok(())
+    }
+
+    /// Settlement waits for the voting window (if any) to close.
+    fn require_window_closed(env: &Env, policy: &EscrowPolicy, vote_start: u64) -> Result<(), Error> {
+        if policy.vote_window > 0 && env.ledger().timestamp() < vote_start + policy.vote_window {
+            return Err(Error::WrongVotingPhase);
+        }
+
+        Ok(())
+    }
+
+    /// Window boundaries of the current vote round (current milestone for milestone escrows).
+    fn voting_windows(escrow: &EscrowData) -> VotingWindows {
+        let mut vote_start = escrow.vote_start;
+        if escrow.kind == EscrowKind::Milestone {
+            vote_start = 0;
+            for i in 0..escrow.milestones.len() {
+                let m = escrow.milestones.get(i).unwrap();
+                if m.status == MilestoneStatus::Pending {
+                    vote_start = m.vote_start;
+                    break;
+                }
+            }
+        }
+
+        let mut windows = VotingWindows { vote_start, vote_end: 0, commit_end: 0, reveal_end: 0 };
+        if vote_start == 0 {
+            return windows;
+        }
+        if escrow.commit_window > 0 {
+            windows.commit_end = vote_start + escrow.commit_window;
+            windows.reveal_end = windows.commit_end + escrow.reveal_window;
+            windows.vote_end = windows.reveal_end;
+        } else if escrow.policy.vote_window > 0 {
+            windows.vote_end = vote_start + escrow.policy.vote_window;
+        }
+        windows
+    }
+
+    /// Refund the unreleased remainder to the donor and mark the escrow `Refunded`.
+    /// `reward` pays voters who flagged the disputed round as a scam.
+    fn settle_refund(env: &Env, escrow: &mut EscrowData, reward: bool) {
+        let refund_amount = escrow.locked_amount - escrow.released_amount;
+
+        let votes_key = Self::disputed_votes_key(escrow);
+        for i in 0..escrow.milestones.len() {
+            let mut m = escrow.milestones.get(i).unwrap();
+            if m.status == MilestoneStatus::Frozen {
+                m.status = MilestoneStatus::Refunded;
+                escrow.milestones.set(i, m);
+            }
         }
 
         escrow.status = EscrowStatus::Refunded;
-        env.storage().persistent().set(&DataKey::Escrow(task_id.clone()), &escrow);
+        env.storage().persistent().set(&DataKey::Escrow(escrow.task_id.clone()), escrow);
 
         // Update global stats
         let total_locked: i128 = env.storage().persistent()
             .get(&DataKey::TotalLocked).unwrap_or(0);
         env.storage().persistent().set(&DataKey::TotalLocked,
-            &(total_locked - escrow.locked_amount));
+            &(total_locked - refund_amount));
 
+        let fee = Self::take_fee(env, escrow, refund_amount, true);
         let total_refunded: i128 = env.storage().persistent()
             .get(&DataKey::TotalRefunded).unwrap_or(0);
         env.storage().persistent().set(&DataKey::TotalRefunded,
-            &(total_refunded + escrow.locked_amount));
+            &(total_refunded + refund_amount - fee));
+
+        let mut rep = Self::load_reputation(env, &escrow.ngo);
+        rep.refunded_count += 1;
+        rep.total_refunded += refund_amount;
+        Self::save_reputation(env, &escrow.ngo, rep);
+
+        // Reward accurate voters (those who correctly voted scam).
+        // Expired escrows were never decided by a vote, so nobody is rewarded
+        // or slashed and all bonds are returned.
+        if reward {
+            Self::settle_bonds(env, &escrow.task_id, Some(true));
+            Self::reward_voters(env, escrow, &votes_key, true);
+        } else {
+            Self::settle_bonds(env, &escrow.task_id, None);
+        }
 
-        // Reward accurate voters (those who correctly voted scam)
-        Self::reward_voters(&env, &task_id, true);
+        // Return the locked funds to the donor, or pro-rata to each contributor
+        if escrow.kind == EscrowKind::Pooled {
+            Self::refund_contributors(env, escrow, refund_amount - fee);
+        } else {
+            Self::pay_out(env, &escrow.token, &escrow.donor, refund_amount - fee);
+        }
+        Self::issue_settlement_receipts(env, escrow, refund_amount - fee);
 
         env.events().publish(
             (symbol_short!("escrow"), symbol_short!("refund")),
-            (task_id, escrow.donor, escrow.locked_amount),
+            (escrow.task_id.clone(), escrow.donor.clone(), refund_amount),
         );
     }
 
-    // ── QUERIES ─────────────────────────────────────────────────
+    /// Release the disputed funds to the NGO after a successful appeal.
+    /// Standard and pooled escrows release the frozen remainder. Milestone escrows
+    /// release only the disputed milestone; the later milestones, which never had
+    /// a proof or a vote, go back to Pending and the escrow reopens.
+    /// Voters who judged the disputed round "real" are the correct ones.
+    fn settle_appeal_release(env: &Env, escrow: &mut EscrowData) {
+        let votes_key = Self::disputed_votes_key(escrow);
+        let now = env.ledger().timestamp();
 
-    /// Get escrow details by task ID.
-    pub fn get_escrow(env: Env, task_id: String) -> EscrowData {
-        env.storage().persistent()
-            .get(&DataKey::Escrow(task_id))
-            .expect("Escrow not found")
+        let amount = if escrow.kind == EscrowKind::Milestone {
+            let mut amount: i128 = 0;
+            let mut disputed = true;
+            for i in 0..escrow.milestones.len() {
+                let mut m = escrow.milestones.get(i).unwrap();
+                if m.status != MilestoneStatus::Frozen {
+                    continue;
+                }
+                if disputed {
+                    m.status = MilestoneStatus::Released;
+                    m.released_at = now;
+                    amount = m.amount;
+                    disputed = false;
+                } else {
+                    m.status = MilestoneStatus::Pending;
+                }
+                escrow.milestones.set(i, m);
+            }
+            amount
+        } else {
+            escrow.locked_amount - escrow.released_amount
+        };
+
+        escrow.released_amount += amount;
+        if escrow.released_amount == escrow.locked_amount {
+            escrow.status = EscrowStatus::Released;
+            escrow.released_at = now;
+        } else {
+            escrow.status = EscrowStatus::Locked;
+            escrow.dispute_start = 0;
+        }
+        env.storage().persistent().set(&DataKey::Escrow(escrow.task_id.clone()), escrow);
+
+        let fee = Self::take_fee(env, escrow, amount, false);
+        Self::record_release(env, &escrow.ngo, amount, fee, false);
+        if escrow.kind != EscrowKind::Milestone {
+            Self::settle_bonds(env, &escrow.task_id, Some(false));
+        }
+        Self::reward_voters(env, escrow, &votes_key, false);
+        Self::pay_out(env, &escrow.token, &escrow.ngo, amount - fee);
+        if escrow.status == EscrowStatus::Released {
+            Self::issue_settlement_receipts(env, escrow, 0);
+        }
+
+        env.events().publish(
+            (symbol_short!("escrow"), symbol_short!("release")),
+            (escrow.task_id.clone(), escrow.ngo.clone(), amount),
+        );
     }
 
-    /// Get all escrows for an NGO.
-    pub fn get_ngo_escrows(env: Env, ngo: Address) -> Vec<String> {
-        env.storage().persistent()
-            .get(&DataKey::NgoEscrows(ngo))
-            .unwrap_or(Vec::new(&env))
+    /// The disputed vote round: the first frozen milestone, or the single escrow round.
+    fn disputed_votes_key(escrow: &EscrowData) -> DataKey {
+        for i in 0..escrow.milestones.len() {
+            if escrow.milestones.get(i).unwrap().status == MilestoneStatus::Frozen {
+                return DataKey::MilestoneVotes(escrow.task_id.clone(), i);
+            }
+        }
+        DataKey::Votes(escrow.task_id.clone())
     }
 
-    /// Get all escrows for a donor.
-    pub fn get_donor_escrows(env: Env, donor: Address) -> Vec<String> {
-        env.storage().persistent()
-            .get(&DataKey::DonorEscrows(donor))
-            .unwrap_or(Vec::new(&env))
+    /// Pay a pooled escrow's refund out to its contributors, pro-rata.
+    fn refund_contributors(env: &Env, escrow: &EscrowData, refund_amount: i128) {
+        let contributions: Vec<Contribution> = env.storage().persistent()
+            .get(&DataKey::Contributions(escrow.task_id.clone())).unwrap_or(Vec::new(env));
+
+        let shares = Self::pro_rata_shares(env, escrow, &contributions, refund_amount);
+        for i in 0..contributions.len() {
+            Self::pay_out(env, &escrow.token, &contributions.get(i).unwrap().donor, shares.get(i).unwrap());
+        }
     }
 
-    /// Get votes for a specific escrow.
-    pub fn get_votes(env: Env, task_id: String) -> Vec<VoteRecord> {
-        env.storage().persistent()
-            .get(&DataKey::Votes(task_id))
-            .unwrap_or(Vec::new(&env))
+    /// Split `amount` pro-rata by contribution; the last contributor absorbs
+    /// any rounding remainder.
+    fn pro_rata_shares(env: &Env, escrow: &EscrowData, contributions: &Vec<Contribution>, amount: i128) -> Vec<i128> {
+        let mut shares: Vec<i128> = Vec::new(env);
+        let mut assigned: i128 = 0;
+        for i in 0..contributions.len() {
+            let share = if i + 1 == contributions.len() {
+                amount - assigned
+            } else {
+                amount * contributions.get(i).unwrap().amount / escrow.locked_amount
+            };
+            assigned += share;
+            shares.push_back(share);
+        }
+        shares
     }
 
-    /// Get voter accuracy stats.
-    pub fn get_voter_stats(env: Env, voter: Address) -> VoterAccuracy {
-        env.storage().persistent()
-            .get(&DataKey::VoterStats(voter))
+    /// Record a receipt for `donor` and append it to their receipt buckets.
+    fn issue_receipt(env: &Env, escrow: &EscrowData, donor: &Address, kind: ReceiptKind, amount: i128, refunded: i128) {
+        let id: u32 = env.storage().persistent().get(&DataKey::ReceiptCount).unwrap_or(0) + 1;
+        let receipt = Receipt {
+            id,
+            kind,
+            donor: donor.clone(),
+            task_id: escrow.task_id.clone(),
+            ngo: escrow.ngo.clone(),
+            token: escrow.token.clone(),
+            amount,
+            refunded,
+            donated_at: escrow.created_at,
+            issued_at: env.ledger().timestamp(),
+            outcome: escrow.status,
+        };
+        env.storage().persistent().set(&DataKey::Receipt(id), &receipt);
+        env.storage().persistent().set(&DataKey::ReceiptCount, &id);
+
+        let count: u32 = env.storage().persistent()
+            .get(&DataKey::DonorReceiptCount(donor.clone())).unwrap_or(0);
+        let key = DataKey::DonorReceiptBucket(donor.clone(), count / INDEX_BUCKET_SIZE);
+        let mut bucket: Vec<u32> = env.storage().persistent()
+            .get(&key).unwrap_or(Vec::new(env));
+        bucket.push_back(id);
+        env.storage().persistent().set(&key, &bucket);
+        env.storage().persistent().set(&DataKey::DonorReceiptCount(donor.clone()), &(count + 1));
+        env.storage().persistent().set(&DataKey::DonorHasReceipt(donor.clone(), escrow.task_id.clone()), &true);
+
+        env.events().publish(
+            (symbol_short!("escrow"), symbol_short!("receipt")),
+            (id, donor.clone(), escrow.task_id.clone(), kind),
+        );
+    }
+
+    /// Issue settlement receipts once an escrow reaches its final status.
+    /// `refunded` is the total returned to donors, split pro-rata for pooled escrows.
+    fn issue_settlement_receipts(env: &Env, escrow: &EscrowData, refunded: i128) {
+        if escrow.kind != EscrowKind::Pooled {
+            Self::issue_receipt(env, escrow, &escrow.donor, ReceiptKind::Settlement, escrow.locked_amount, refunded);
+            return;
+        }
+
+        let contributions: Vec<Contribution> = env.storage().persistent()
+            .get(&DataKey::Contributions(escrow.task_id.clone())).unwrap_or(Vec::new(env));
+        let shares = Self::pro_rata_shares(env, escrow, &contributions, refunded);
+        for i in 0..contributions.len() {
+            let c = contributions.get(i).unwrap();
+            Self::issue_receipt(env, escrow, &c.donor, ReceiptKind::Settlement, c.amount, shares.get(i).unwrap());
+        }
+    }
+
+    /// Delegators of `delegate` who may be counted on this escrow: accepted
+    /// delegators whose effective delegate for the NGO is `delegate`, who are not
+    /// parties to the escrow and who are not already in `votes` as a voter or as
+    /// someone else's delegator, and who still pass the voter gate now (a reaper
+    /// suspended or deregistered since accepting no longer counts). The gate's
+    /// cross-contract calls are bounded: both lists are capped at MAX_DELEGATORS.
+    fn collect_delegators(env: &Env, escrow: &EscrowData, votes: &Vec<VoteRecord>, delegate: &Address) -> Vec<Address> {
+        let mut counted: Vec<Address> = Vec::new(env);
+        for scope in [Some(escrow.ngo.clone()), None] {
+            let candidates: Vec<Address> = env.storage().persistent()
+                .get(&DataKey::Delegators(delegate.clone(), scope.clone()))
+                .unwrap_or(Vec::new(env));
+            for i in 0..candidates.len() {
+                let candidate = candidates.get(i).unwrap();
+                if scope.is_none() && env.storage().persistent()
+                    .has(&DataKey::Delegation(candidate.clone(), Some(escrow.ngo.clone())))
+                {
+                    continue; // per-NGO delegation wins
+                }
+                let mut already = counted.contains(&candidate);
+                for j in 0..votes.len() {
+                    let record = votes.get(j).unwrap();
+                    already = already || record.voter == candidate || record.delegators.contains(&candidate);
+                }
+                if !already
+                    && Self::check_party(env, escrow, &candidate).is_ok()
+                    && Self::check_reaper(env, &candidate).is_ok()
+                {
+                    counted.push_back(candidate);
+                }
+            }
+        }
+        counted
+    }
+
+    fn remove_delegator(env: &Env, delegate: &Address, ngo: &Option<Address>, delegator: &Address) {
+        let key = DataKey::Delegators(delegate.clone(), ngo.clone());
+        let mut delegators: Vec<Address> = env.storage().persistent()
+            .get(&key).unwrap_or(Vec::new(env));
+        if let Some(pos) = delegators.first_index_of(delegator) {
+            delegators.remove(pos);
+            env.storage().persistent().set(&key, &delegators);
+        }
+    }
+
+    /// Send the platform fee on a payout of `amount` to the treasury and return it
+    /// (0 when no fee is configured, or for refunds unless `on_refund`).
+    fn take_fee(env: &Env, escrow: &EscrowData, amount: i128, is_refund: bool) -> i128 {
+        let config: FeeConfig = match env.storage().persistent().get(&DataKey::FeeConfig) {
+            Some(c) => c,
+            None => return 0,
+        };
+        if is_refund && !config.on_refund {
+            return 0;
+        }
+        let fee = amount * config.fee_bps as i128 / 10_000;
+        if fee <= 0 {
+            return 0;
+        }
+
+        Self::pay_out(env, &escrow.token, &config.treasury, fee);
+        if config.route == FeeRoute::Deposit {
+            env.invoke_contract::<()>(
+                &config.treasury, &symbol_short!("deposit"),
+                (env.current_contract_address(), fee, escrow.task_id.clone()).into_val(env),
+            );
+        }
+
+        let total_fees: i128 = env.storage().persistent()
+            .get(&DataKey::TotalFees).unwrap_or(0);
+        env.storage().persistent().set(&DataKey::TotalFees, &(total_fees + fee));
+
+        env.events().publish(
+            (symbol_short!("escrow"), symbol_short!("fee")),
+            (escrow.task_id.clone(), config.treasury, fee),
+        );
+        fee
+    }
+
+    /// Take `units` vote bonds from `voter` if the escrow uses bonded voting.
+    fn lock_bond(env: &Env, task_id: &String, voter: &Address, units: u32) {
+        let bond: VoteBond = match env.storage().persistent().get(&DataKey::VoteBond(task_id.clone())) {
+            Some(b) => b,
+            None => return,
+        };
+        if units == 0 {
+            return;
+        }
+
+        token::TokenClient::new(env, &bond.token)
+            .transfer(voter, env.current_contract_address(), &(bond.amount * units as i128));
+    }
+
+    /// Return vote bonds once the escrow is decided. With a verdict, incorrect
+    /// voters lose `slash_pct`% per bond unit and unrevealed commitments lose the
+    /// whole bond; the slashed total is split evenly per correct bond unit (a
+    /// delegate's vote holds one unit per voter it counted; rounding dust stays in
+    /// the reward pool). Without a verdict, or with no correct voter, every bond
+    /// is returned in full.
+    fn settle_bonds(env: &Env, task_id: &String, verdict: Option<bool>) {
+        let bond: VoteBond = match env.storage().persistent().get(&DataKey::VoteBond(task_id.clone())) {
+            Some(b) => b,
+            None => return,
+        };
+        let votes: Vec<VoteRecord> = env.storage().persistent()
+            .get(&DataKey::Votes(task_id.clone())).unwrap_or(Vec::new(env));
+        let commits: Vec<VoteCommit> = env.storage().persistent()
+            .get(&DataKey::Commits(task_id.clone())).unwrap_or(Vec::new(env));
+
+        let slash = bond.amount * bond.slash_pct as i128 / 100;
+        let mut winners: i128 = 0;
+        let mut pot: i128 = 0;
+        for i in 0..votes.len() {
+            let vote = votes.get(i).unwrap();
+            let units = vote.bonded / bond.amount;
+            if let Some(was_scam) = verdict {
+                if vote.is_scam == was_scam {
+                    winners += units;
+                } else {
+                    pot += slash * units;
+                }
+            }
+        }
+        for i in 0..commits.len() {
+            if !commits.get(i).unwrap().revealed && verdict.is_some() {
+                pot += bond.amount;
+            }
+        }
+        let slashing = winners > 0;
+        let share = if slashing { pot / winners } else { 0 };
+
+        for i in 0..votes.len() {
+            let vote = votes.get(i).unwrap();
+            let units = vote.bonded / bond.amount;
+            let payout = match verdict {
+                Some(was_scam) if slashing && vote.is_scam == was_scam => vote.bonded + share * units,
+                Some(_) if slashing => vote.bonded - slash * units,
+                _ => vote.bonded,
+            };
+            Self::return_bond(env, &bond, &vote.voter, payout, vote.bonded);
+        }
+        for i in 0..commits.len() {
+            let commit = commits.get(i).unwrap();
+            if !commit.revealed {
+                let payout = if slashing { 0 } else { bond.amount };
+                Self::return_bond(env, &bond, &commit.voter, payout, bond.amount);
+            }
+        }
+
+        if slashing && pot > share * winners {
+            let pool: i128 = env.storage().persistent()
+                .get(&DataKey::RewardPool).unwrap_or(0);
+            env.storage().persistent().set(&DataKey::RewardPool, &(pool + pot - share * winners));
+        }
+
+        env.events().publish(
+            (symbol_short!("escrow"), symbol_short!("bonds")),
+            (task_id.clone(), if slashing { pot } else { 0 }),
+        );
+    }
+
+    /// Pay back `payout` of the `locked` bond and record the voter's net win/loss.
+    fn return_bond(env: &Env, bond: &VoteBond, voter: &Address, payout: i128, locked: i128) {
+        if payout > 0 {
+            token::TokenClient::new(env, &bond.token)
+                .transfer(&env.current_contract_address(), voter, &payout);
+        }
+
+        let mut stats: VoterAccuracy = env.storage().persistent()
+            .get(&DataKey::VoterStats(voter.clone()))
             .unwrap_or(VoterAccuracy {
                 correct_votes: 0,
                 total_votes: 0,
                 rewards_earned: 0,
-            })
-    }
-
-    /// Get global platform stats.
-    pub fn get_platform_stats(env: Env) -> (i128, i128, i128) {
-        let locked: i128 = env.storage().persistent()
-            .get(&DataKey::TotalLocked).unwrap_or(0);
-        let released: i128 = env.storage().persistent()
-            .get(&DataKey::TotalReleased).unwrap_or(0);
-        let refunded: i128 = env.storage().persistent()
-            .get(&DataKey::TotalRefunded).unwrap_or(0);
-        (locked, released, refunded)
+                net_bond: 0,
+            });
+        stats.net_bond += payout - locked;
+        env.storage().persistent().set(&DataKey::VoterStats(voter.clone()), &stats);
     }
 
-    /// Get all escrow task IDs.
-    pub fn get_all_escrow_ids(env: Env) -> Vec<String> {
-        env.storage().persistent()
-            .get(&DataKey::EscrowIndex)
-            .unwrap_or(Vec::new(&env))
+    /// Transfer escrowed funds held by this contract to `to`.
+    fn pay_out(env: &Env, token: &Address, to: &Address, amount: i128) {
+        if amount > 0 {
+            token::TokenClient::new(env, token)
+                .transfer(&env.current_contract_address(), to, &amount);
+        }
     }
 
-    // ── INTERNAL HELPERS ────────────────────────────────────────
-
     /// Reward voters who voted correctly.
     /// If was_scam=true, those who voted "scam" were correct.
     /// If was_scam=false, those who voted "real" were correct.
-    fn reward_voters(env: &Env, task_id: &String, was_scam: bool) {
+    /// Each correct voter is paid the configured Reiatsu reward, if any.
+    fn reward_voters(env: &Env, escrow: &EscrowData, votes_key: &DataKey, was_scam: bool) {
         let votes: Vec<VoteRecord> = env.storage().persistent()
-            .get(&DataKey::Votes(task_id.clone()))
+            .get(votes_key)
             .unwrap_or(Vec::new(env));
+        let config: Option<RewardConfig> = env.storage().persistent()
+            .get(&DataKey::RewardConfig);
 
         for i in 0..votes.len() {
             let vote = votes.get(i).unwrap();
@@ -501,11 +3373,51 @@ impl EscrowContract {
                         correct_votes: 0,
                         total_votes: 0,
                         rewards_earned: 0,
+                        net_bond: 0,
                     });
                 stats.correct_votes += 1;
-                stats.rewards_earned += 10; // 10 Reiatsu per correct vote
+                if let Some(config) = &config {
+                    stats.rewards_earned += Self::pay_reward(
+                        env, config, &escrow.task_id, &vote.voter, escrow.policy.voter_reward,
+                    );
+                }
                 env.storage().persistent().set(&DataKey::VoterStats(vote.voter.clone()), &stats);
             }
         }
     }
+
+    /// Pay one voter reward in Reiatsu. Returns the amount actually paid.
+    /// Pool rewards draw down `RewardPool`; once it cannot cover a reward the
+    /// voter is paid nothing rather than blocking settlement.
+    fn pay_reward(env: &Env, config: &RewardConfig, task_id: &String, voter: &Address, amount: i128) -> i128 {
+        if amount <= 0 {
+            return 0;
+        }
+
+        let escrow_addr = env.current_contract_address();
+        match config.source {
+            RewardSource::Mint => {
+                env.invoke_contract::<()>(
+                    &config.token, &symbol_short!("mint"),
+                    (escrow_addr, voter.clone(), amount).into_val(env),
+                );
+            }
+            RewardSource::Pool => {
+                let pool: i128 = env.storage().persistent()
+                    .get(&DataKey::RewardPool).unwrap_or(0);
+                if pool < amount {
+                    return 0;
+                }
+                env.storage().persistent().set(&DataKey::RewardPool, &(pool - amount));
+                token::TokenClient::new(env, &config.token)
+                    .transfer(&escrow_addr, voter, &amount);
+            }
+        }
+
+        env.events().publish(
+            (symbol_short!("escrow"), symbol_short!("reward")),
+            (task_id.clone(), voter.clone(), amount),
+        );
+        amount
+    }
 }
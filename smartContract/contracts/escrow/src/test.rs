@@ -1,7 +1,12 @@
 #![cfg(test)]
 use super::*;
 use soroban_sdk::testutils::{Address as _, Ledger};
-use soroban_sdk::{Env, Address, String};
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{vec, Env, Address, BytesN, String};
+use reiatsu_token::{ReiatsuToken, ReiatsuTokenClient};
+use soul_badge::{SoulBadge, SoulBadgeClient};
+use soul_reaper_registry::{SoulReaperRegistry, SoulReaperRegistryClient};
+use division_treasury::{DivisionTreasury, DivisionTreasuryClient};
 
 fn setup_contract() -> (Env, Address, Address, Address, Address, EscrowContractClient<'static>) {
     let env = Env::default();
@@ -24,12 +29,76 @@ fn setup_contract() -> (Env, Address, Address, Address, Address, EscrowContractC
     (env, admin, donor, ngo, contract_id, client)
 }
 
+/// Funding terms for `create_escrow` / `create_pool_escrow`.
+fn funding(total_amount: i128, locked_amount: i128, deadline: u64) -> EscrowFunding {
+    EscrowFunding { total_amount, locked_amount, deadline }
+}
+
+/// Register a Stellar Asset Contract, accept it for escrows and fund the donor with it.
+fn setup_token(env: &Env, client: &EscrowContractClient<'static>, donor: &Address) -> Address {
+    let issuer = Address::generate(env);
+    let token = env.register_stellar_asset_contract_v2(issuer).address();
+    client.set_token_accepted(&token, &true);
+    StellarAssetClient::new(env, &token).mint(donor, &1_000_000);
+    token
+}
+
+/// Register a Reiatsu token and configure it as the voter reward source.
+/// In `Mint` mode the escrow becomes a minter; in `Pool` mode the pool is pre-funded.
+fn setup_rewards(
+    env: &Env,
+    client: &EscrowContractClient<'static>,
+    escrow_id: &Address,
+    source: RewardSource,
+) -> ReiatsuTokenClient<'static> {
+    let reiatsu_id = env.register(ReiatsuToken, ());
+    let reiatsu = ReiatsuTokenClient::new(env, &reiatsu_id);
+    let reiatsu_admin = Address::generate(env);
+    reiatsu.initialize(&reiatsu_admin);
+
+    client.set_reward_config(&reiatsu_id, &10, &source);
+    match source {
+        RewardSource::Mint => reiatsu.add_minter(&reiatsu_admin, escrow_id),
+        RewardSource::Pool => {
+            let funder = Address::generate(env);
+            reiatsu.mint(&reiatsu_admin, &funder, &1_000);
+            client.fund_reward_pool(&funder, &1_000);
+        }
+    }
+    reiatsu
+}
+
+/// Register a reaper registry and badge contract and gate voting on them.
+fn setup_voter_gate(
+    env: &Env,
+    client: &EscrowContractClient<'static>,
+    min_badges: u32,
+) -> (Address, SoulReaperRegistryClient<'static>, SoulBadgeClient<'static>) {
+    let registry_id = env.register(SoulReaperRegistry, ());
+    let registry = SoulReaperRegistryClient::new(env, &registry_id);
+    let badge_id = env.register(SoulBadge, ());
+    let badges = SoulBadgeClient::new(env, &badge_id);
+
+    let gate_admin = Address::generate(env);
+    registry.initialize(&gate_admin);
+    badges.initialize(&gate_admin);
+
+    client.set_voter_gate(&registry_id, &Some(badge_id), &min_badges);
+    (gate_admin, registry, badges)
+}
+
+/// Register `voter` as an active reaper in division 1.
+fn register_reaper(env: &Env, registry: &SoulReaperRegistryClient<'static>, voter: &Address) {
+    registry.register(voter, &String::from_str(env, "Reaper"), &1, &Symbol::new(env, "seated"), &100);
+}
+
 #[test]
 fn test_create_escrow() {
-    let (env, _admin, donor, ngo, _cid, client) = setup_contract();
+    let (env, _admin, donor, ngo, contract_id, client) = setup_contract();
+    let token = setup_token(&env, &client, &donor);
 
     let task_id = String::from_str(&env, "task_001");
-    client.create_escrow(&donor, &ngo, &1000, &500, &task_id, &9999999);
+    client.create_escrow(&donor, &ngo, &token, &task_id, &funding(1000, 500, 9999999), &None);
 
     let escrow = client.get_escrow(&task_id);
     assert_eq!(escrow.total_amount, 1000);
@@ -49,18 +118,60 @@ fn test_create_escrow() {
     assert_eq!(donor_escrows.len(), 1);
 
     // Platform stats
-    let (locked, released, refunded) = client.get_platform_stats();
+    let (locked, released, refunded, _) = client.get_platform_stats();
     assert_eq!(locked, 500);
     assert_eq!(released, 0);
     assert_eq!(refunded, 0);
+
+    // Locked funds moved into contract custody
+    let token_client = TokenClient::new(&env, &token);
+    assert_eq!(token_client.balance(&donor), 1_000_000 - 500);
+    assert_eq!(token_client.balance(&contract_id), 500);
+}
+
+#[test]
+fn test_escrow_only_takes_accepted_tokens() {
+    let (env, _admin, donor, ngo, _cid, client) = setup_contract();
+    let token = setup_token(&env, &client, &donor);
+    let other = env.register_stellar_asset_contract_v2(Address::generate(&env)).address();
+    StellarAssetClient::new(&env, &other).mint(&donor, &1_000_000);
+    assert!(client.is_token_accepted(&token));
+    assert!(!client.is_token_accepted(&other));
+
+    // A token in another unit would skew the shared totals
+    let task_id = String::from_str(&env, "task_070");
+    assert_eq!(
+        client.try_create_escrow(&donor, &ngo, &other, &task_id, &funding(1000, 500, 9999999), &None),
+        Err(Ok(Error::InvalidConfig))
+    );
+    assert_eq!(
+        client.try_create_milestone_escrow(&donor, &ngo, &other, &task_id, &three_milestones(&env), &None),
+        Err(Ok(Error::InvalidConfig))
+    );
+    assert_eq!(
+        client.try_create_pool_escrow(&donor, &ngo, &other, &task_id, &funding(1000, 500, 9999999), &None),
+        Err(Ok(Error::InvalidConfig))
+    );
+    assert_eq!(client.get_platform_stats().0, 0);
+
+    // Withdrawing a token stops new escrows but lets open ones settle
+    client.create_escrow(&donor, &ngo, &token, &task_id, &funding(1000, 500, 9999999), &None);
+    client.set_token_accepted(&token, &false);
+    assert_eq!(
+        client.try_create_escrow(&donor, &ngo, &token, &String::from_str(&env, "task_071"), &funding(1000, 500, 9999999), &None),
+        Err(Ok(Error::InvalidConfig))
+    );
+    client.donor_release(&task_id);
+    assert_eq!(client.get_escrow(&task_id).status, EscrowStatus::DonorReleased);
 }
 
 #[test]
 fn test_submit_proof() {
     let (env, _admin, donor, ngo, _cid, client) = setup_contract();
+    let token = setup_token(&env, &client, &donor);
 
     let task_id = String::from_str(&env, "task_002");
-    client.create_escrow(&donor, &ngo, &2000, &1000, &task_id, &9999999);
+    client.create_escrow(&donor, &ngo, &token, &task_id, &funding(2000, 1000, 9999999), &None);
 
     let proof = String::from_str(&env, "QmProofHash123");
     client.submit_proof(&task_id, &proof);
@@ -72,10 +183,13 @@ fn test_submit_proof() {
 
 #[test]
 fn test_vote_and_release() {
-    let (env, _admin, donor, ngo, _cid, client) = setup_contract();
+    let (env, _admin, donor, ngo, contract_id, client) = setup_contract();
+    let token = setup_token(&env, &client, &donor);
+    let reiatsu = setup_rewards(&env, &client, &contract_id, RewardSource::Mint);
 
     let task_id = String::from_str(&env, "task_003");
-    client.create_escrow(&donor, &ngo, &1000, &500, &task_id, &9999999);
+    client.create_escrow(&donor, &ngo, &token, &task_id, &funding(1000, 500, 9999999), &None);
+    client.submit_proof(&task_id, &String::from_str(&env, "QmProof"));
 
     // 3 community members vote "real"
     let voter1 = Address::generate(&env);
@@ -98,23 +212,33 @@ fn test_vote_and_release() {
     assert!(escrow.released_at > 0);
 
     // Platform stats updated
-    let (locked, released, _refunded) = client.get_platform_stats();
+    let (locked, released, _refunded, _) = client.get_platform_stats();
     assert_eq!(locked, 0);
     assert_eq!(released, 500);
 
+    // NGO received the locked funds
+    let token_client = TokenClient::new(&env, &token);
+    assert_eq!(token_client.balance(&ngo), 500);
+    assert_eq!(token_client.balance(&contract_id), 0);
+
     // Voters should get accuracy rewards
     let stats1 = client.get_voter_stats(&voter1);
     assert_eq!(stats1.correct_votes, 1);
     assert_eq!(stats1.total_votes, 1);
     assert_eq!(stats1.rewards_earned, 10);
+    assert_eq!(reiatsu.balance(&voter1), 10);
+    assert_eq!(reiatsu.total_supply(), 30);
 }
 
 #[test]
 fn test_vote_dispute_and_refund() {
-    let (env, _admin, donor, ngo, _cid, client) = setup_contract();
+    let (env, _admin, donor, ngo, contract_id, client) = setup_contract();
+    let token = setup_token(&env, &client, &donor);
+    let reiatsu = setup_rewards(&env, &client, &contract_id, RewardSource::Pool);
 
     let task_id = String::from_str(&env, "task_004");
-    client.create_escrow(&donor, &ngo, &1000, &500, &task_id, &9999999);
+    client.create_escrow(&donor, &ngo, &token, &task_id, &funding(1000, 500, 9999999), &None);
+    client.submit_proof(&task_id, &String::from_str(&env, "QmProof"));
 
     // 3 community members vote "scam"
     let voter1 = Address::generate(&env);
@@ -150,72 +274,83 @@ fn test_vote_dispute_and_refund() {
     assert_eq!(escrow.status, EscrowStatus::Refunded);
 
     // Platform stats
-    let (locked, _released, refunded) = client.get_platform_stats();
+    let (locked, _released, refunded, _) = client.get_platform_stats();
     assert_eq!(locked, 0);
     assert_eq!(refunded, 500);
 
+    // Donor got the locked funds back
+    let token_client = TokenClient::new(&env, &token);
+    assert_eq!(token_client.balance(&donor), 1_000_000);
+    assert_eq!(token_client.balance(&ngo), 0);
+
     // Scam voters get accuracy rewards
     let stats1 = client.get_voter_stats(&voter1);
     assert_eq!(stats1.correct_votes, 1);
     assert_eq!(stats1.rewards_earned, 10);
+    assert_eq!(reiatsu.balance(&voter1), 10);
+    assert_eq!(reiatsu.balance(&contract_id), 1_000 - 30);
 }
 
 #[test]
-#[should_panic(expected = "Cannot vote on own escrow")]
 fn test_cannot_vote_own_escrow() {
     let (env, _admin, donor, ngo, _cid, client) = setup_contract();
+    let token = setup_token(&env, &client, &donor);
 
     let task_id = String::from_str(&env, "task_005");
-    client.create_escrow(&donor, &ngo, &1000, &500, &task_id, &9999999);
+    client.create_escrow(&donor, &ngo, &token, &task_id, &funding(1000, 500, 9999999), &None);
 
     // Donor tries to vote on own escrow
-    client.vote(&task_id, &donor, &false);
+    assert_eq!(client.try_vote(&task_id, &donor, &false), Err(Ok(Error::CannotVoteOwnEscrow)));
 }
 
 #[test]
-#[should_panic(expected = "Already voted")]
 fn test_cannot_double_vote() {
     let (env, _admin, donor, ngo, _cid, client) = setup_contract();
+    let token = setup_token(&env, &client, &donor);
 
     let task_id = String::from_str(&env, "task_006");
-    client.create_escrow(&donor, &ngo, &1000, &500, &task_id, &9999999);
+    client.create_escrow(&donor, &ngo, &token, &task_id, &funding(1000, 500, 9999999), &None);
+    client.submit_proof(&task_id, &String::from_str(&env, "QmProof"));
 
     let voter = Address::generate(&env);
     client.vote(&task_id, &voter, &false);
-    client.vote(&task_id, &voter, &true); // should panic
+    assert_eq!(client.try_vote(&task_id, &voter, &true), Err(Ok(Error::AlreadyVoted)));
 }
 
 #[test]
-#[should_panic(expected = "Escrow already exists for this task")]
 fn test_cannot_create_duplicate_escrow() {
     let (env, _admin, donor, ngo, _cid, client) = setup_contract();
+    let token = setup_token(&env, &client, &donor);
 
     let task_id = String::from_str(&env, "task_007");
-    client.create_escrow(&donor, &ngo, &1000, &500, &task_id, &9999999);
-    client.create_escrow(&donor, &ngo, &2000, &1000, &task_id, &9999999); // should panic
+    client.create_escrow(&donor, &ngo, &token, &task_id, &funding(1000, 500, 9999999), &None);
+    assert_eq!(client.try_create_escrow(&donor, &ngo, &token, &task_id, &funding(2000, 1000, 9999999), &None), Err(Ok(Error::EscrowAlreadyExists)));
 }
 
 #[test]
-#[should_panic(expected = "Not enough votes")]
 fn test_cannot_release_without_min_votes() {
     let (env, _admin, donor, ngo, _cid, client) = setup_contract();
+    let token = setup_token(&env, &client, &donor);
 
     let task_id = String::from_str(&env, "task_008");
-    client.create_escrow(&donor, &ngo, &1000, &500, &task_id, &9999999);
+    client.create_escrow(&donor, &ngo, &token, &task_id, &funding(1000, 500, 9999999), &None);
+    client.submit_proof(&task_id, &String::from_str(&env, "QmProof"));
 
     // Only 1 vote (min is 3)
     let voter = Address::generate(&env);
     client.vote(&task_id, &voter, &false);
 
-    client.release(&task_id); // should panic
+    assert_eq!(client.try_release(&task_id), Err(Ok(Error::NotEnoughVotes)));
 }
 
 #[test]
 fn test_mixed_votes_release() {
     let (env, _admin, donor, ngo, _cid, client) = setup_contract();
+    let token = setup_token(&env, &client, &donor);
 
     let task_id = String::from_str(&env, "task_009");
-    client.create_escrow(&donor, &ngo, &1000, &500, &task_id, &9999999);
+    client.create_escrow(&donor, &ngo, &token, &task_id, &funding(1000, 500, 9999999), &None);
+    client.submit_proof(&task_id, &String::from_str(&env, "QmProof"));
 
     // 4 real, 1 scam = 80% real → should release
     let v1 = Address::generate(&env);
@@ -247,14 +382,15 @@ fn test_mixed_votes_release() {
 #[test]
 fn test_multiple_escrows_for_same_ngo() {
     let (env, _admin, donor, ngo, _cid, client) = setup_contract();
+    let token = setup_token(&env, &client, &donor);
 
     let t1 = String::from_str(&env, "task_010");
     let t2 = String::from_str(&env, "task_011");
     let t3 = String::from_str(&env, "task_012");
 
-    client.create_escrow(&donor, &ngo, &1000, &500, &t1, &9999999);
-    client.create_escrow(&donor, &ngo, &2000, &1000, &t2, &9999999);
-    client.create_escrow(&donor, &ngo, &3000, &1500, &t3, &9999999);
+    client.create_escrow(&donor, &ngo, &token, &t1, &funding(1000, 500, 9999999), &None);
+    client.create_escrow(&donor, &ngo, &token, &t2, &funding(2000, 1000, 9999999), &None);
+    client.create_escrow(&donor, &ngo, &token, &t3, &funding(3000, 1500, 9999999), &None);
 
     let ngo_escrows = client.get_ngo_escrows(&ngo);
     assert_eq!(ngo_escrows.len(), 3);
@@ -263,6 +399,1277 @@ fn test_multiple_escrows_for_same_ngo() {
     assert_eq!(all.len(), 3);
 
     // Total locked = 500 + 1000 + 1500 = 3000
-    let (locked, _, _) = client.get_platform_stats();
+    let (locked, _, _, _) = client.get_platform_stats();
     assert_eq!(locked, 3000);
 }
+
+#[test]
+fn test_commit_reveal_vote_and_release() {
+    let (env, _admin, donor, ngo, _cid, client) = setup_contract();
+    let token = setup_token(&env, &client, &donor);
+
+    let task_id = String::from_str(&env, "task_013");
+    client.create_escrow(&donor, &ngo, &token, &task_id, &funding(1000, 500, 9999999), &None);
+    client.submit_proof(&task_id, &String::from_str(&env, "QmProof"));
+    client.enable_commit_reveal(&task_id, &3600, &3600);
+
+    let voters = [
+        Address::generate(&env),
+        Address::generate(&env),
+        Address::generate(&env),
+        Address::generate(&env),
+    ];
+    let salt = BytesN::from_array(&env, &[7u8; 32]);
+    for v in voters.iter() {
+        let commitment = client.compute_commitment(v, &task_id, &1, &false, &salt);
+        client.commit_vote(&task_id, v, &commitment);
+    }
+
+    // Nothing counted while votes are sealed
+    let escrow = client.get_escrow(&task_id);
+    assert_eq!(escrow.real_votes, 0);
+    assert_eq!(escrow.unrevealed_commits, 4);
+
+    // Reveal phase
+    env.ledger().with_mut(|li| li.timestamp += 3600);
+    for v in voters.iter().take(3) {
+        client.reveal_vote(&task_id, v, &false, &salt);
+    }
+
+    let escrow = client.get_escrow(&task_id);
+    assert_eq!(escrow.real_votes, 3);
+    assert_eq!(escrow.unrevealed_commits, 1);
+    assert_eq!(client.get_commits(&task_id).len(), 4);
+
+    // Tally is final after the reveal phase
+    env.ledger().with_mut(|li| li.timestamp += 3600);
+    client.release(&task_id);
+    assert_eq!(client.get_escrow(&task_id).status, EscrowStatus::Released);
+
+    // Unrevealed voter is not counted
+    assert_eq!(client.get_voter_stats(&voters[3]).total_votes, 0);
+}
+
+#[test]
+fn test_reveal_must_match_commitment() {
+    let (env, _admin, donor, ngo, _cid, client) = setup_contract();
+    let token = setup_token(&env, &client, &donor);
+
+    let task_id = String::from_str(&env, "task_014");
+    client.create_escrow(&donor, &ngo, &token, &task_id, &funding(1000, 500, 9999999), &None);
+    client.submit_proof(&task_id, &String::from_str(&env, "QmProof"));
+    client.enable_commit_reveal(&task_id, &3600, &3600);
+
+    let voter = Address::generate(&env);
+    let salt = BytesN::from_array(&env, &[9u8; 32]);
+    client.commit_vote(&task_id, &voter, &client.compute_commitment(&voter, &task_id, &1, &true, &salt));
+
+    env.ledger().with_mut(|li| li.timestamp += 3600);
+    assert_eq!(client.try_reveal_vote(&task_id, &voter, &false, &salt), Err(Ok(Error::CommitmentMismatch)));
+}
+
+#[test]
+fn test_copied_commitment_cannot_be_revealed() {
+    let (env, _admin, donor, ngo, _cid, client) = setup_contract();
+    let token = setup_token(&env, &client, &donor);
+
+    let task_id = String::from_str(&env, "task_057");
+    client.create_escrow(&donor, &ngo, &token, &task_id, &funding(1000, 500, 9999999), &None);
+    client.submit_proof(&task_id, &String::from_str(&env, "QmProof"));
+    client.enable_commit_reveal(&task_id, &3600, &3600);
+
+    // The copier replays the honest voter's commitment, then their revealed preimage
+    let (honest, copier) = (Address::generate(&env), Address::generate(&env));
+    let salt = BytesN::from_array(&env, &[3u8; 32]);
+    let commitment = client.compute_commitment(&honest, &task_id, &1, &true, &salt);
+    client.commit_vote(&task_id, &honest, &commitment);
+    client.commit_vote(&task_id, &copier, &commitment);
+
+    env.ledger().with_mut(|li| li.timestamp += 3600);
+    client.reveal_vote(&task_id, &honest, &true, &salt);
+    assert_eq!(client.try_reveal_vote(&task_id, &copier, &true, &salt), Err(Ok(Error::CommitmentMismatch)));
+    assert_eq!(client.get_escrow(&task_id).scam_votes, 1);
+
+    // A commitment for another escrow or proof round does not match either
+    assert_ne!(commitment, client.compute_commitment(&honest, &String::from_str(&env, "task_058"), &1, &true, &salt));
+    assert_ne!(commitment, client.compute_commitment(&honest, &task_id, &2, &true, &salt));
+}
+
+#[test]
+fn test_direct_vote_rejected_in_commit_reveal_mode() {
+    let (env, _admin, donor, ngo, _cid, client) = setup_contract();
+    let token = setup_token(&env, &client, &donor);
+
+    let task_id = String::from_str(&env, "task_015");
+    client.create_escrow(&donor, &ngo, &token, &task_id, &funding(1000, 500, 9999999), &None);
+    client.enable_commit_reveal(&task_id, &3600, &3600);
+
+    let voter = Address::generate(&env);
+    assert_eq!(client.try_vote(&task_id, &voter, &false), Err(Ok(Error::WrongVotingMode)));
+}
+
+#[test]
+fn test_empty_reward_pool_does_not_block_release() {
+    let (env, _admin, donor, ngo, _cid, client) = setup_contract();
+    let token = setup_token(&env, &client, &donor);
+
+    let reiatsu_id = env.register(ReiatsuToken, ());
+    ReiatsuTokenClient::new(&env, &reiatsu_id).initialize(&Address::generate(&env));
+    client.set_reward_config(&reiatsu_id, &10, &RewardSource::Pool);
+
+    let task_id = String::from_str(&env, "task_016");
+    client.create_escrow(&donor, &ngo, &token, &task_id, &funding(1000, 500, 9999999), &None);
+    client.submit_proof(&task_id, &String::from_str(&env, "QmProof"));
+
+    let voter = Address::generate(&env);
+    client.vote(&task_id, &voter, &false);
+    client.vote(&task_id, &Address::generate(&env), &false);
+    client.vote(&task_id, &Address::generate(&env), &false);
+    client.release(&task_id);
+
+    let stats = client.get_voter_stats(&voter);
+    assert_eq!(stats.correct_votes, 1);
+    assert_eq!(stats.rewards_earned, 0);
+}
+
+#[test]
+fn test_reward_pool_never_pays_from_escrowed_funds() {
+    let (env, _admin, donor, ngo, contract_id, client) = setup_contract();
+
+    // Donations are escrowed in the same token the rewards are paid in
+    let reiatsu_id = env.register(ReiatsuToken, ());
+    let reiatsu = ReiatsuTokenClient::new(&env, &reiatsu_id);
+    let reiatsu_admin = Address::generate(&env);
+    reiatsu.initialize(&reiatsu_admin);
+    reiatsu.mint(&reiatsu_admin, &donor, &1_000);
+    client.set_reward_config(&reiatsu_id, &10, &RewardSource::Pool);
+    client.set_token_accepted(&reiatsu_id, &true);
+
+    let funder = Address::generate(&env);
+    reiatsu.mint(&reiatsu_admin, &funder, &15);
+    client.fund_reward_pool(&funder, &15);
+    assert_eq!(client.get_reward_pool(), 15);
+
+    let task_id = String::from_str(&env, "task_054");
+    client.create_escrow(&donor, &ngo, &reiatsu_id, &task_id, &funding(1000, 500, 9999999), &None);
+    client.submit_proof(&task_id, &String::from_str(&env, "QmProof"));
+    let voters = [Address::generate(&env), Address::generate(&env), Address::generate(&env)];
+    for voter in voters.iter() {
+        client.vote(&task_id, voter, &false);
+    }
+    client.release(&task_id);
+
+    // Only the first reward is covered by the pool; the escrow paid the NGO in full
+    assert_eq!(reiatsu.balance(&voters[0]), 10);
+    assert_eq!(reiatsu.balance(&voters[1]), 0);
+    assert_eq!(reiatsu.balance(&ngo), 500);
+    assert_eq!(client.get_reward_pool(), 5);
+    assert_eq!(reiatsu.balance(&contract_id), 5);
+}
+
+fn three_milestones(env: &Env) -> Vec<MilestoneSpec> {
+    vec![
+        env,
+        MilestoneSpec { amount: 100, deadline: 2_000_000 },
+        MilestoneSpec { amount: 200, deadline: 3_000_000 },
+        MilestoneSpec { amount: 300, deadline: 4_000_000 },
+    ]
+}
+
+#[test]
+fn test_milestone_partial_release() {
+    let (env, _admin, donor, ngo, contract_id, client) = setup_contract();
+    let token = setup_token(&env, &client, &donor);
+    let token_client = TokenClient::new(&env, &token);
+
+    let task_id = String::from_str(&env, "task_017");
+    client.create_milestone_escrow(&donor, &ngo, &token, &task_id, &three_milestones(&env), &None);
+
+    let escrow = client.get_escrow(&task_id);
+    assert_eq!(escrow.kind, EscrowKind::Milestone);
+    assert_eq!(escrow.locked_amount, 600);
+    assert_eq!(escrow.milestones.len(), 3);
+    assert_eq!(token_client.balance(&contract_id), 600);
+
+    // Milestone 0: proof, votes, release
+    client.submit_milestone_proof(&task_id, &0, &String::from_str(&env, "QmM0"));
+    for _ in 0..3 {
+        client.vote_milestone(&task_id, &0, &Address::generate(&env), &false);
+    }
+    client.release_milestone(&task_id, &0);
+
+    let escrow = client.get_escrow(&task_id);
+    assert_eq!(escrow.status, EscrowStatus::Locked);
+    assert_eq!(escrow.released_amount, 100);
+    assert_eq!(escrow.milestones.get(0).unwrap().status, MilestoneStatus::Released);
+    assert_eq!(escrow.milestones.get(1).unwrap().status, MilestoneStatus::Pending);
+    assert_eq!(token_client.balance(&ngo), 100);
+
+    let (locked, released, _, _) = client.get_platform_stats();
+    assert_eq!(locked, 500);
+    assert_eq!(released, 100);
+
+    // Same voter may vote again in the next milestone's round
+    let voter = Address::generate(&env);
+    assert_eq!(client.try_vote_milestone(&task_id, &1, &voter, &false), Err(Ok(Error::ProofRequired)));
+    client.submit_milestone_proof(&task_id, &1, &String::from_str(&env, "QmM1"));
+    client.vote_milestone(&task_id, &1, &voter, &false);
+    assert_eq!(client.get_milestone_votes(&task_id, &1).len(), 1);
+}
+
+#[test]
+fn test_milestone_dispute_freezes_remaining() {
+    let (env, _admin, donor, ngo, _cid, client) = setup_contract();
+    let token = setup_token(&env, &client, &donor);
+    let token_client = TokenClient::new(&env, &token);
+
+    let task_id = String::from_str(&env, "task_018");
+    client.create_milestone_escrow(&donor, &ngo, &token, &task_id, &three_milestones(&env), &None);
+
+    client.submit_milestone_proof(&task_id, &0, &String::from_str(&env, "QmM0"));
+    for _ in 0..3 {
+        client.vote_milestone(&task_id, &0, &Address::generate(&env), &false);
+    }
+    client.release_milestone(&task_id, &0);
+
+    // Milestone 1 is flagged as a scam
+    client.submit_milestone_proof(&task_id, &1, &String::from_str(&env, "QmM1"));
+    for _ in 0..3 {
+        client.vote_milestone(&task_id, &1, &Address::generate(&env), &true);
+    }
+    client.dispute_milestone(&task_id, &1);
+
+    let escrow = client.get_escrow(&task_id);
+    assert_eq!(escrow.status, EscrowStatus::Disputed);
+    assert_eq!(escrow.milestones.get(0).unwrap().status, MilestoneStatus::Released);
+    assert_eq!(escrow.milestones.get(1).unwrap().status, MilestoneStatus::Frozen);
+    assert_eq!(escrow.milestones.get(2).unwrap().status, MilestoneStatus::Frozen);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = escrow.dispute_start + DEFAULT_LOCK_DURATION + 1;
+    });
+    client.refund(&task_id);
+
+    // Only the unreleased remainder goes back to the donor
+    assert_eq!(token_client.balance(&donor), 1_000_000 - 100);
+    assert_eq!(token_client.balance(&ngo), 100);
+
+    let (locked, released, refunded, _) = client.get_platform_stats();
+    assert_eq!(locked, 0);
+    assert_eq!(released, 100);
+    assert_eq!(refunded, 500);
+
+    let escrow = client.get_escrow(&task_id);
+    assert_eq!(escrow.milestones.get(2).unwrap().status, MilestoneStatus::Refunded);
+}
+
+#[test]
+fn test_milestones_release_in_order() {
+    let (env, _admin, donor, ngo, _cid, client) = setup_contract();
+    let token = setup_token(&env, &client, &donor);
+
+    let task_id = String::from_str(&env, "task_019");
+    client.create_milestone_escrow(&donor, &ngo, &token, &task_id, &three_milestones(&env), &None);
+
+    assert_eq!(client.try_vote_milestone(&task_id, &1, &Address::generate(&env), &false), Err(Ok(Error::NotCurrentMilestone)));
+}
+
+#[test]
+fn test_expired_escrow_refunds_without_admin() {
+    let env = Env::default();
+    env.ledger().with_mut(|li| li.timestamp = 1_000_000);
+
+    let contract_id = env.register(EscrowContract, ());
+    let client = EscrowContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let donor = Address::generate(&env);
+    let ngo = Address::generate(&env);
+
+    env.mock_all_auths();
+    client.initialize(&admin);
+    let token = setup_token(&env, &client, &donor);
+
+    let task_id = String::from_str(&env, "task_020");
+    client.create_escrow(&donor, &ngo, &token, &task_id, &funding(1000, 500, 1_500_000), &None);
+
+    // From here on nobody signs anything: settlement is permissionless
+    env.set_auths(&[]);
+    env.ledger().with_mut(|li| li.timestamp = 1_500_001);
+    client.settle_expired(&task_id);
+    assert_eq!(client.get_escrow(&task_id).status, EscrowStatus::Expired);
+
+    client.refund(&task_id);
+    assert_eq!(client.get_escrow(&task_id).status, EscrowStatus::Refunded);
+    assert_eq!(TokenClient::new(&env, &token).balance(&donor), 1_000_000);
+
+    let (locked, _, refunded, _) = client.get_platform_stats();
+    assert_eq!(locked, 0);
+    assert_eq!(refunded, 500);
+}
+
+#[test]
+fn test_cannot_expire_after_proof() {
+    let (env, _admin, donor, ngo, _cid, client) = setup_contract();
+    let token = setup_token(&env, &client, &donor);
+
+    let task_id = String::from_str(&env, "task_021");
+    client.create_escrow(&donor, &ngo, &token, &task_id, &funding(1000, 500, 1_500_000), &None);
+    client.submit_proof(&task_id, &String::from_str(&env, "QmProof"));
+
+    env.ledger().with_mut(|li| li.timestamp = 1_500_001);
+    assert_eq!(client.try_settle_expired(&task_id), Err(Ok(Error::ProofSubmitted)));
+}
+
+#[test]
+fn test_expired_milestone_freezes_remaining() {
+    let (env, _admin, donor, ngo, _cid, client) = setup_contract();
+    let token = setup_token(&env, &client, &donor);
+
+    let task_id = String::from_str(&env, "task_022");
+    client.create_milestone_escrow(&donor, &ngo, &token, &task_id, &three_milestones(&env), &None);
+
+    client.submit_milestone_proof(&task_id, &0, &String::from_str(&env, "QmM0"));
+    for _ in 0..3 {
+        client.vote_milestone(&task_id, &0, &Address::generate(&env), &false);
+    }
+    client.release_milestone(&task_id, &0);
+
+    // Milestone 1 misses its deadline
+    env.ledger().with_mut(|li| li.timestamp = 3_000_001);
+    client.settle_expired(&task_id);
+
+    let escrow = client.get_escrow(&task_id);
+    assert_eq!(escrow.status, EscrowStatus::Expired);
+    assert_eq!(escrow.milestones.get(1).unwrap().status, MilestoneStatus::Frozen);
+
+    client.refund(&task_id);
+    assert_eq!(TokenClient::new(&env, &token).balance(&donor), 1_000_000 - 100);
+}
+
+/// Create an escrow, flag it as a scam and dispute it. Returns the scam voters.
+fn disputed_escrow(
+    env: &Env,
+    client: &EscrowContractClient<'static>,
+    donor: &Address,
+    ngo: &Address,
+    token: &Address,
+    task_id: &String,
+) -> [Address; 3] {
+    client.create_escrow(donor, ngo, token, task_id, &funding(1000, 500, 9999999), &None);
+    client.submit_proof(task_id, &String::from_str(env, "QmProof"));
+    let voters = [Address::generate(env), Address::generate(env), Address::generate(env)];
+    for v in voters.iter() {
+        client.vote(task_id, v, &true);
+    }
+    client.dispute(task_id);
+    voters
+}
+
+#[test]
+fn test_appeal_restores_release() {
+    let (env, _admin, donor, ngo, _cid, client) = setup_contract();
+    let token = setup_token(&env, &client, &donor);
+    let arbs = vec![&env, Address::generate(&env), Address::generate(&env), Address::generate(&env)];
+    client.set_arbitrators(&arbs);
+
+    let task_id = String::from_str(&env, "task_023");
+    let voters = disputed_escrow(&env, &client, &donor, &ngo, &token, &task_id);
+
+    client.appeal(&task_id, &vec![&env, String::from_str(&env, "QmCounterEvidence")]);
+    assert_eq!(client.get_escrow(&task_id).status, EscrowStatus::Appealed);
+
+    client.arbitrate(&task_id, &arbs.get(0).unwrap(), &true);
+    client.arbitrate(&task_id, &arbs.get(1).unwrap(), &true);
+
+    let escrow = client.get_escrow(&task_id);
+    assert_eq!(escrow.status, EscrowStatus::Released);
+    assert_eq!(TokenClient::new(&env, &token).balance(&ngo), 500);
+    assert!(client.get_appeal(&task_id).unwrap().resolved_at > 0);
+
+    // Scam voters were wrong according to arbitration
+    let stats = client.get_voter_stats(&voters[0]);
+    assert_eq!(stats.correct_votes, 0);
+    assert_eq!(stats.total_votes, 1);
+
+    let (locked, released, refunded, _) = client.get_platform_stats();
+    assert_eq!(locked, 0);
+    assert_eq!(released, 500);
+    assert_eq!(refunded, 0);
+}
+
+#[test]
+fn test_milestone_appeal_releases_only_the_disputed_milestone() {
+    let (env, _admin, donor, ngo, contract_id, client) = setup_contract();
+    let token = setup_token(&env, &client, &donor);
+    let token_client = TokenClient::new(&env, &token);
+    let arb = Address::generate(&env);
+    client.set_arbitrators(&vec![&env, arb.clone()]);
+
+    let task_id = String::from_str(&env, "task_063");
+    client.create_milestone_escrow(&donor, &ngo, &token, &task_id, &three_milestones(&env), &None);
+    client.submit_milestone_proof(&task_id, &0, &String::from_str(&env, "QmM0"));
+    let voters = [Address::generate(&env), Address::generate(&env), Address::generate(&env)];
+    for voter in voters.iter() {
+        client.vote_milestone(&task_id, &0, voter, &true);
+    }
+    client.dispute_milestone(&task_id, &0);
+    client.appeal(&task_id, &vec![&env, String::from_str(&env, "QmCounterEvidence")]);
+    client.arbitrate(&task_id, &arb, &true);
+
+    // Only milestone 0 is paid; the rest still need their own proof and vote
+    let escrow = client.get_escrow(&task_id);
+    assert_eq!(escrow.status, EscrowStatus::Locked);
+    assert_eq!(escrow.released_amount, 100);
+    assert_eq!(escrow.milestones.get(0).unwrap().status, MilestoneStatus::Released);
+    assert_eq!(escrow.milestones.get(1).unwrap().status, MilestoneStatus::Pending);
+    assert_eq!(escrow.milestones.get(2).unwrap().status, MilestoneStatus::Pending);
+    assert_eq!(token_client.balance(&ngo), 100);
+    assert_eq!(token_client.balance(&contract_id), 500);
+    assert_eq!(client.get_voter_stats(&voters[0]).correct_votes, 0);
+
+    assert_eq!(client.try_vote_milestone(&task_id, &1, &Address::generate(&env), &false), Err(Ok(Error::ProofRequired)));
+    client.submit_milestone_proof(&task_id, &1, &String::from_str(&env, "QmM1"));
+    for _ in 0..3 {
+        client.vote_milestone(&task_id, &1, &Address::generate(&env), &false);
+    }
+    client.release_milestone(&task_id, &1);
+    assert_eq!(token_client.balance(&ngo), 300);
+}
+
+#[test]
+fn test_appeal_rejected_confirms_refund() {
+    let (env, _admin, donor, ngo, _cid, client) = setup_contract();
+    let token = setup_token(&env, &client, &donor);
+    let arbs = vec![&env, Address::generate(&env), Address::generate(&env), Address::generate(&env)];
+    client.set_arbitrators(&arbs);
+
+    let task_id = String::from_str(&env, "task_024");
+    let voters = disputed_escrow(&env, &client, &donor, &ngo, &token, &task_id);
+
+    client.appeal(&task_id, &vec![&env, String::from_str(&env, "QmCounterEvidence")]);
+    client.arbitrate(&task_id, &arbs.get(0).unwrap(), &true);
+    client.arbitrate(&task_id, &arbs.get(1).unwrap(), &false);
+    assert_eq!(client.get_escrow(&task_id).status, EscrowStatus::Appealed);
+    client.arbitrate(&task_id, &arbs.get(2).unwrap(), &false);
+
+    assert_eq!(client.get_escrow(&task_id).status, EscrowStatus::Refunded);
+    assert_eq!(TokenClient::new(&env, &token).balance(&donor), 1_000_000);
+    assert_eq!(client.get_voter_stats(&voters[0]).correct_votes, 1);
+}
+
+#[test]
+fn test_cannot_refund_during_appeal() {
+    let (env, _admin, donor, ngo, _cid, client) = setup_contract();
+    let token = setup_token(&env, &client, &donor);
+    client.set_arbitrators(&vec![&env, Address::generate(&env)]);
+
+    let task_id = String::from_str(&env, "task_025");
+    let voters = disputed_escrow(&env, &client, &donor, &ngo, &token, &task_id);
+    client.appeal(&task_id, &vec![&env, String::from_str(&env, "QmCounterEvidence")]);
+
+    env.ledger().with_mut(|li| li.timestamp += ARBITRATION_WINDOW - 1);
+    assert_eq!(client.try_refund(&task_id), Err(Ok(Error::AppealPending)));
+
+    // An undecided appeal lapses and the dispute stands
+    env.ledger().with_mut(|li| li.timestamp += 1);
+    let arb = client.get_appeal(&task_id).unwrap().panel.get(0).unwrap();
+    assert_eq!(client.try_arbitrate(&task_id, &arb, &true), Err(Ok(Error::AppealWindowClosed)));
+
+    // Appealing early does not shorten the dispute lock
+    assert_eq!(client.try_refund(&task_id), Err(Ok(Error::LockNotExpired)));
+    let dispute_start = client.get_escrow(&task_id).dispute_start;
+    env.ledger().with_mut(|li| li.timestamp = dispute_start + DEFAULT_LOCK_DURATION);
+    client.refund(&task_id);
+    assert_eq!(client.get_escrow(&task_id).status, EscrowStatus::Refunded);
+    assert_eq!(TokenClient::new(&env, &token).balance(&donor), 1_000_000);
+    assert_eq!(client.get_voter_stats(&voters[0]).correct_votes, 1);
+    assert!(client.get_appeal(&task_id).unwrap().resolved_at > 0);
+}
+
+#[test]
+fn test_appeal_panel_is_fixed_when_filed() {
+    let (env, _admin, donor, ngo, _cid, client) = setup_contract();
+    let token = setup_token(&env, &client, &donor);
+    let original = Address::generate(&env);
+    client.set_arbitrators(&vec![&env, original.clone()]);
+
+    let task_id = String::from_str(&env, "task_062");
+    disputed_escrow(&env, &client, &donor, &ngo, &token, &task_id);
+    client.appeal(&task_id, &vec![&env, String::from_str(&env, "QmCounterEvidence")]);
+
+    // Replacing the panel does not change who decides this case
+    let replacement = Address::generate(&env);
+    client.set_arbitrators(&vec![&env, replacement.clone()]);
+    assert_eq!(client.try_arbitrate(&task_id, &replacement, &true), Err(Ok(Error::NotArbitrator)));
+    client.arbitrate(&task_id, &original, &true);
+    assert_eq!(client.get_escrow(&task_id).status, EscrowStatus::Released);
+}
+
+#[test]
+fn test_only_active_reapers_can_vote() {
+    let (env, _admin, donor, ngo, _cid, client) = setup_contract();
+    let token = setup_token(&env, &client, &donor);
+    let (gate_admin, registry, _badges) = setup_voter_gate(&env, &client, 0);
+
+    let task_id = String::from_str(&env, "task_026");
+    client.create_escrow(&donor, &ngo, &token, &task_id, &funding(1000, 500, 9999999), &None);
+    client.submit_proof(&task_id, &String::from_str(&env, "QmProof"));
+
+    let stranger = Address::generate(&env);
+    assert_eq!(client.try_vote(&task_id, &stranger, &false), Err(Ok(Error::NotRegisteredReaper)));
+
+    let suspended = Address::generate(&env);
+    register_reaper(&env, &registry, &suspended);
+    registry.suspend(&gate_admin, &suspended);
+    assert_eq!(client.try_vote(&task_id, &suspended, &false), Err(Ok(Error::ReaperNotActive)));
+
+    let reaper = Address::generate(&env);
+    register_reaper(&env, &registry, &reaper);
+    client.vote(&task_id, &reaper, &false);
+    assert_eq!(client.get_escrow(&task_id).real_votes, 1);
+}
+
+#[test]
+fn test_voter_gate_requires_min_badges() {
+    let (env, _admin, donor, ngo, _cid, client) = setup_contract();
+    let token = setup_token(&env, &client, &donor);
+    let (_gate_admin, registry, badges) = setup_voter_gate(&env, &client, 1);
+
+    let task_id = String::from_str(&env, "task_027");
+    client.create_escrow(&donor, &ngo, &token, &task_id, &funding(1000, 500, 9999999), &None);
+    client.submit_proof(&task_id, &String::from_str(&env, "QmProof"));
+
+    let reaper = Address::generate(&env);
+    register_reaper(&env, &registry, &reaper);
+    assert_eq!(client.try_vote(&task_id, &reaper, &true), Err(Ok(Error::NotEnoughBadges)));
+
+    badges.mint(&reaper, &String::from_str(&env, "mission_1"), &String::from_str(&env, "seated"));
+    client.vote(&task_id, &reaper, &true);
+    assert_eq!(client.get_escrow(&task_id).scam_votes, 1);
+}
+
+#[test]
+fn test_bonded_votes_slash_wrong_voters() {
+    let (env, _admin, donor, ngo, contract_id, client) = setup_contract();
+    let token = setup_token(&env, &client, &donor);
+    let reiatsu = setup_rewards(&env, &client, &contract_id, RewardSource::Mint);
+
+    let task_id = String::from_str(&env, "task_028");
+    client.create_escrow(&donor, &ngo, &token, &task_id, &funding(1000, 500, 9999999), &None);
+    client.submit_proof(&task_id, &String::from_str(&env, "QmProof"));
+    client.set_vote_bond(&task_id, &100, &50);
+
+    let honest = [Address::generate(&env), Address::generate(&env), Address::generate(&env)];
+    let wrong = Address::generate(&env);
+    for voter in honest.iter().chain([&wrong]) {
+        reiatsu.mint(&contract_id, voter, &1_000);
+    }
+    for voter in honest.iter() {
+        client.vote(&task_id, voter, &false);
+    }
+    client.vote(&task_id, &wrong, &true);
+    assert_eq!(reiatsu.balance(&wrong), 900);
+
+    client.release(&task_id);
+
+    // 50 slashed from the wrong voter, split 16 each (dust stays in the pool),
+    // plus the 10 Reiatsu correct-vote reward
+    assert_eq!(reiatsu.balance(&honest[0]), 1_026);
+    assert_eq!(reiatsu.balance(&wrong), 950);
+    assert_eq!(client.get_voter_stats(&honest[0]).net_bond, 16);
+    assert_eq!(client.get_voter_stats(&wrong).net_bond, -50);
+    assert_eq!(reiatsu.balance(&contract_id), 2);
+    assert_eq!(client.get_reward_pool(), 2);
+}
+
+#[test]
+fn test_vote_bond_must_be_set_before_voting() {
+    let (env, _admin, donor, ngo, contract_id, client) = setup_contract();
+    let token = setup_token(&env, &client, &donor);
+    setup_rewards(&env, &client, &contract_id, RewardSource::Mint);
+
+    let task_id = String::from_str(&env, "task_029");
+    client.create_escrow(&donor, &ngo, &token, &task_id, &funding(1000, 500, 9999999), &None);
+    client.submit_proof(&task_id, &String::from_str(&env, "QmProof"));
+    assert_eq!(client.try_set_vote_bond(&task_id, &100, &101), Err(Ok(Error::InvalidConfig)));
+
+    client.vote(&task_id, &Address::generate(&env), &false);
+    assert_eq!(client.try_set_vote_bond(&task_id, &100, &50), Err(Ok(Error::VotingStarted)));
+}
+
+#[test]
+fn test_pooled_escrow_refunds_pro_rata() {
+    let (env, _admin, donor, ngo, contract_id, client) = setup_contract();
+    let token = setup_token(&env, &client, &donor);
+    let token_client = TokenClient::new(&env, &token);
+    let backer = Address::generate(&env);
+    StellarAssetClient::new(&env, &token).mint(&backer, &1_000_000);
+
+    let task_id = String::from_str(&env, "task_030");
+    client.create_pool_escrow(&donor, &ngo, &token, &task_id, &funding(1000, 300, 9999999), &None);
+    client.contribute(&task_id, &backer, &200);
+    client.contribute(&task_id, &backer, &300);
+    assert_eq!(client.try_contribute(&task_id, &backer, &201), Err(Ok(Error::ExceedsTarget)));
+
+    let escrow = client.get_escrow(&task_id);
+    assert_eq!(escrow.kind, EscrowKind::Pooled);
+    assert_eq!(escrow.locked_amount, 800);
+    assert_eq!(client.get_contributions(&task_id).len(), 2);
+    assert_eq!(client.get_donor_escrows(&backer).len(), 1);
+    assert_eq!(token_client.balance(&contract_id), 800);
+
+    client.submit_proof(&task_id, &String::from_str(&env, "QmProof"));
+    // Contributors cannot vote on the pool they funded
+    assert_eq!(client.try_vote(&task_id, &backer, &true), Err(Ok(Error::CannotVoteOwnEscrow)));
+
+    for _ in 0..3 {
+        client.vote(&task_id, &Address::generate(&env), &true);
+    }
+    assert_eq!(client.try_contribute(&task_id, &backer, &100), Err(Ok(Error::FundingClosed)));
+    client.dispute(&task_id);
+    env.ledger().with_mut(|li| li.timestamp += DEFAULT_LOCK_DURATION + 1);
+    client.refund(&task_id);
+
+    assert_eq!(token_client.balance(&donor), 1_000_000);
+    assert_eq!(token_client.balance(&backer), 1_000_000);
+    assert_eq!(token_client.balance(&contract_id), 0);
+
+    // One receipt per contribution, then a settlement receipt with the refunded share
+    let receipts = client.get_donor_receipts(&backer, &0, &10);
+    assert_eq!(receipts.len(), 3);
+    let settlement = receipts.get(2).unwrap();
+    assert_eq!(settlement.kind, ReceiptKind::Settlement);
+    assert_eq!((settlement.amount, settlement.refunded), (500, 500));
+}
+
+#[test]
+fn test_paginated_escrow_queries() {
+    let (env, _admin, donor, ngo, _cid, client) = setup_contract();
+    let token = setup_token(&env, &client, &donor);
+
+    // 55 escrows span two index buckets
+    for i in 0..55u8 {
+        let task_id = String::from_bytes(&env, &[b'p', b'0' + i / 10, b'0' + i % 10]);
+        client.create_escrow(&donor, &ngo, &token, &task_id, &funding(10, 5, 1_500_000), &None);
+    }
+    env.ledger().with_mut(|li| li.timestamp = 1_500_001);
+    client.settle_expired(&String::from_str(&env, "p07"));
+    client.settle_expired(&String::from_str(&env, "p52"));
+
+    assert_eq!(client.get_escrow_count(&IndexScope::All), 55);
+    assert_eq!(client.get_all_escrow_ids().len(), 55);
+    assert_eq!(client.get_donor_escrows(&donor).len(), 55);
+
+    let ids = client.get_escrow_ids_page(&IndexScope::Ngo(ngo.clone()), &45, &10);
+    assert_eq!(ids.len(), 10);
+    assert_eq!(ids.get(0).unwrap(), String::from_str(&env, "p45"));
+    assert_eq!(ids.get(9).unwrap(), String::from_str(&env, "p54"));
+
+    let page = client.get_escrows_page(&IndexScope::All, &0, &100, &Some(EscrowStatus::Expired));
+    assert_eq!(page.escrows.len(), 1);
+    assert_eq!(page.escrows.get(0).unwrap().task_id, String::from_str(&env, "p07"));
+    assert_eq!(page.next_cursor, 50);
+    assert_eq!(page.total, 55);
+
+    let page = client.get_escrows_page(&IndexScope::All, &page.next_cursor, &50, &None);
+    assert_eq!(page.escrows.len(), 5);
+    assert_eq!(page.escrows.get(2).unwrap().status, EscrowStatus::Expired);
+    assert_eq!(page.next_cursor, page.total);
+
+    // One donation receipt per escrow; pages cross the bucket boundary
+    assert_eq!(client.get_donor_receipt_count(&donor), 55);
+    assert_eq!(client.get_donor_receipts(&donor, &0, &100).len(), 50);
+    let receipts = client.get_donor_receipts(&donor, &45, &10);
+    assert_eq!(receipts.len(), 10);
+    assert_eq!(receipts.get(0).unwrap().task_id, String::from_str(&env, "p45"));
+    assert_eq!(receipts.get(9).unwrap().task_id, String::from_str(&env, "p54"));
+    assert!(client.verify_receipt(&donor, &String::from_str(&env, "p52")));
+}
+
+#[test]
+fn test_donor_release_pays_ngo_early() {
+    let (env, _admin, donor, ngo, contract_id, client) = setup_contract();
+    let token = setup_token(&env, &client, &donor);
+
+    let task_id = String::from_str(&env, "task_031");
+    client.create_escrow(&donor, &ngo, &token, &task_id, &funding(1000, 500, 9999999), &None);
+    client.donor_release(&task_id);
+
+    let escrow = client.get_escrow(&task_id);
+    assert_eq!(escrow.status, EscrowStatus::DonorReleased);
+    assert_eq!(escrow.released_amount, 500);
+    assert_eq!(TokenClient::new(&env, &token).balance(&ngo), 500);
+    assert_eq!(TokenClient::new(&env, &token).balance(&contract_id), 0);
+    assert_eq!(client.get_platform_stats(), (0, 500, 0, 0));
+
+    // A donor release is no community verdict: it does not move the trust score
+    let rep = client.get_ngo_reputation(&ngo);
+    assert_eq!(rep.released_count, 0);
+    assert_eq!(rep.donor_released_count, 1);
+    assert_eq!(rep.total_donor_released, 500);
+    assert_eq!(rep.trust_score, 50);
+
+    // ...and an NGO cannot fund its own escrow to build one
+    assert_eq!(
+        client.try_create_escrow(&ngo, &ngo, &token, &String::from_str(&env, "task_055"), &funding(1000, 500, 9999999), &None),
+        Err(Ok(Error::DonorIsNgo))
+    );
+}
+
+#[test]
+fn test_cancel_requires_no_votes() {
+    let (env, _admin, donor, ngo, _cid, client) = setup_contract();
+    let token = setup_token(&env, &client, &donor);
+
+    let task_id = String::from_str(&env, "task_032");
+    client.create_escrow(&donor, &ngo, &token, &task_id, &funding(1000, 500, 9999999), &None);
+    client.submit_proof(&task_id, &String::from_str(&env, "QmProof"));
+    client.cancel(&task_id);
+
+    assert_eq!(client.get_escrow(&task_id).status, EscrowStatus::Cancelled);
+    assert_eq!(TokenClient::new(&env, &token).balance(&donor), 1_000_000);
+    assert_eq!(client.get_platform_stats(), (0, 0, 0, 0));
+
+    let voted = String::from_str(&env, "task_033");
+    client.create_escrow(&donor, &ngo, &token, &voted, &funding(1000, 500, 9999999), &None);
+    client.submit_proof(&voted, &String::from_str(&env, "QmProof"));
+    client.vote(&voted, &Address::generate(&env), &false);
+    assert_eq!(client.try_cancel(&voted), Err(Ok(Error::VotingStarted)));
+}
+
+#[test]
+fn test_resubmitted_proof_starts_new_round() {
+    let (env, _admin, donor, ngo, _cid, client) = setup_contract();
+    let token = setup_token(&env, &client, &donor);
+
+    let task_id = String::from_str(&env, "task_034");
+    client.create_escrow(&donor, &ngo, &token, &task_id, &funding(1000, 500, 9999999), &None);
+    client.set_resubmit_mode(&task_id, &ResubmitMode::NewRound);
+
+    client.submit_proof(&task_id, &String::from_str(&env, "QmFirst"));
+    let voter = Address::generate(&env);
+    client.vote(&task_id, &voter, &true);
+    client.vote(&task_id, &Address::generate(&env), &true);
+    assert_eq!(client.get_votes(&task_id).get(0).unwrap().proof_version, 1);
+
+    env.ledger().with_mut(|li| li.timestamp += 60);
+    client.submit_proof(&task_id, &String::from_str(&env, "QmSecond"));
+
+    let escrow = client.get_escrow(&task_id);
+    assert_eq!(escrow.proof_version, 2);
+    assert_eq!(escrow.scam_votes, 0);
+    assert_eq!(client.get_round_votes(&task_id, &1).len(), 2);
+    assert_eq!(client.get_voter_stats(&voter).total_votes, 0);
+
+    let history = client.get_proof_history(&task_id);
+    assert_eq!(history.len(), 2);
+    assert_eq!(history.get(1).unwrap().cid, String::from_str(&env, "QmSecond"));
+    assert_eq!(history.get(1).unwrap().submitted_at, 1_000_060);
+
+    // The same voter judges the new proof afresh
+    client.vote(&task_id, &voter, &false);
+    let votes = client.get_votes(&task_id);
+    assert_eq!(votes.len(), 1);
+    assert_eq!(votes.get(0).unwrap().proof_version, 2);
+}
+
+#[test]
+fn test_resubmitted_proof_resets_tally() {
+    let (env, _admin, donor, ngo, _cid, client) = setup_contract();
+    let token = setup_token(&env, &client, &donor);
+
+    let task_id = String::from_str(&env, "task_035");
+    client.create_escrow(&donor, &ngo, &token, &task_id, &funding(1000, 500, 9999999), &None);
+    client.submit_proof(&task_id, &String::from_str(&env, "QmFirst"));
+    client.vote(&task_id, &Address::generate(&env), &false);
+
+    client.submit_proof(&task_id, &String::from_str(&env, "QmSecond"));
+    assert_eq!(client.get_escrow(&task_id).real_votes, 0);
+    assert_eq!(client.get_votes(&task_id).len(), 0);
+    assert_eq!(client.get_round_votes(&task_id, &1).len(), 0);
+}
+
+#[test]
+fn test_resubmission_cannot_wipe_a_decided_tally() {
+    let (env, _admin, donor, ngo, _cid, client) = setup_contract();
+    let token = setup_token(&env, &client, &donor);
+    let cid = String::from_str(&env, "QmAgain");
+
+    // A dispute-ready tally stays put
+    let decided = String::from_str(&env, "task_059");
+    client.create_escrow(&donor, &ngo, &token, &decided, &funding(1000, 500, 9999999), &None);
+    client.submit_proof(&decided, &String::from_str(&env, "QmProof"));
+    for _ in 0..3 {
+        client.vote(&decided, &Address::generate(&env), &true);
+    }
+    assert_eq!(client.try_submit_proof(&decided, &cid), Err(Ok(Error::TallyDecided)));
+    client.dispute(&decided);
+
+    // Rounds are capped
+    let capped = String::from_str(&env, "task_060");
+    client.create_escrow(&donor, &ngo, &token, &capped, &funding(1000, 500, 9999999), &None);
+    for _ in 0..MAX_PROOF_ROUNDS {
+        client.submit_proof(&capped, &cid);
+    }
+    assert_eq!(client.try_submit_proof(&capped, &cid), Err(Ok(Error::TooManyProofRounds)));
+
+    // Once the window closes the tally is final
+    client.set_vote_window(&3600);
+    let closed = String::from_str(&env, "task_061");
+    client.create_escrow(&donor, &ngo, &token, &closed, &funding(1000, 500, 9999999), &None);
+    client.submit_proof(&closed, &String::from_str(&env, "QmProof"));
+    client.vote(&closed, &Address::generate(&env), &true);
+    env.ledger().with_mut(|li| li.timestamp += 3600);
+    assert_eq!(client.try_submit_proof(&closed, &cid), Err(Ok(Error::WrongVotingPhase)));
+}
+
+#[test]
+fn test_escrow_keeps_policy_from_creation() {
+    let (env, _admin, donor, ngo, _cid, client) = setup_contract();
+    let token = setup_token(&env, &client, &donor);
+
+    let task_id = String::from_str(&env, "task_036");
+    client.create_escrow(&donor, &ngo, &token, &task_id, &funding(1000, 500, 9999999), &None);
+    client.submit_proof(&task_id, &String::from_str(&env, "QmProof"));
+
+    // Raising the global quorum does not apply to the existing escrow
+    client.set_min_votes(&5);
+    for _ in 0..3 {
+        client.vote(&task_id, &Address::generate(&env), &false);
+    }
+    client.release(&task_id);
+    assert_eq!(client.get_escrow(&task_id).policy.min_votes, 3);
+
+    let later = String::from_str(&env, "task_037");
+    client.create_escrow(&donor, &ngo, &token, &later, &funding(1000, 500, 9999999), &None);
+    client.submit_proof(&later, &String::from_str(&env, "QmProof"));
+    for _ in 0..3 {
+        client.vote(&later, &Address::generate(&env), &false);
+    }
+    assert_eq!(client.try_release(&later), Err(Ok(Error::NotEnoughVotes)));
+}
+
+#[test]
+fn test_donor_policy_override_must_be_stricter() {
+    let (env, _admin, donor, ngo, _cid, client) = setup_contract();
+    let token = setup_token(&env, &client, &donor);
+    client.set_dispute_threshold(&70);
+
+    let mut policy = client.get_policy();
+    assert_eq!(policy.dispute_threshold, 70);
+    policy.dispute_threshold = 60;
+    let task_id = String::from_str(&env, "task_038");
+    assert_eq!(
+        client.try_create_escrow(&donor, &ngo, &token, &task_id, &funding(1000, 500, 9999999), &Some(policy.clone())),
+        Err(Ok(Error::PolicyTooLax))
+    );
+
+    policy.dispute_threshold = 80;
+    policy.lock_duration = DEFAULT_LOCK_DURATION * 2;
+    client.create_escrow(&donor, &ngo, &token, &task_id, &funding(1000, 500, 9999999), &Some(policy.clone()));
+    assert_eq!(client.get_escrow(&task_id).policy, policy);
+}
+
+#[test]
+fn test_ngo_reputation_tracks_outcomes() {
+    let (env, _admin, donor, ngo, _cid, client) = setup_contract();
+    let token = setup_token(&env, &client, &donor);
+    assert_eq!(client.get_ngo_reputation(&ngo).trust_score, 50);
+
+    let released = String::from_str(&env, "task_039");
+    client.create_escrow(&donor, &ngo, &token, &released, &funding(1000, 300, 9999999), &None);
+    client.submit_proof(&released, &String::from_str(&env, "QmProof"));
+    for _ in 0..3 {
+        client.vote(&released, &Address::generate(&env), &false);
+    }
+    client.release(&released);
+
+    let refunded = String::from_str(&env, "task_040");
+    disputed_escrow(&env, &client, &donor, &ngo, &token, &refunded);
+    env.ledger().with_mut(|li| li.timestamp += DEFAULT_LOCK_DURATION + 1);
+    client.refund(&refunded);
+
+    let rep = client.get_ngo_reputation(&ngo);
+    assert_eq!(rep.released_count, 1);
+    assert_eq!(rep.disputed_count, 1);
+    assert_eq!(rep.refunded_count, 1);
+    assert_eq!(rep.total_released, 300);
+    assert_eq!(rep.total_refunded, 500);
+    assert_eq!(rep.trust_score, 37);
+
+    // Large escrows now need a better track record
+    client.set_reputation_gate(&50, &400);
+    let large = String::from_str(&env, "task_041");
+    assert_eq!(
+        client.try_create_escrow(&donor, &ngo, &token, &large, &funding(1000, 500, 9999999), &None),
+        Err(Ok(Error::ReputationTooLow))
+    );
+    client.create_escrow(&donor, &ngo, &token, &large, &funding(1000, 100, 9999999), &None);
+    client.submit_proof(&large, &String::from_str(&env, "QmProof"));
+    for _ in 0..3 {
+        client.vote(&large, &Address::generate(&env), &false);
+    }
+    client.release(&large);
+    assert_eq!(client.get_escrow(&large).status, EscrowStatus::Released);
+
+    // A smaller escrow that was allowed through still builds the track record
+    let rep = client.get_ngo_reputation(&ngo);
+    assert_eq!(rep.released_count, 2);
+    assert_eq!(rep.total_released, 400);
+    assert!(rep.trust_score > 37);
+}
+
+#[test]
+fn test_delegated_votes_carry_weight() {
+    let (env, _admin, donor, ngo, _cid, client) = setup_contract();
+    let token = setup_token(&env, &client, &donor);
+
+    let task_id = String::from_str(&env, "task_042");
+    client.create_escrow(&donor, &ngo, &token, &task_id, &funding(1000, 500, 9999999), &None);
+    client.submit_proof(&task_id, &String::from_str(&env, "QmProof"));
+
+    let (x, y, z) = (Address::generate(&env), Address::generate(&env), Address::generate(&env));
+    let (a, b, c) = (Address::generate(&env), Address::generate(&env), Address::generate(&env));
+    for (delegator, delegate, scope) in [(&a, &x, None), (&b, &x, None), (&c, &x, None), (&c, &y, Some(ngo.clone()))] {
+        client.delegate(delegator, delegate, &scope);
+        client.accept_delegation(delegate, delegator, &scope);
+    } // C's per-NGO delegation to Y wins for this escrow
+    assert_eq!(client.try_delegate(&a, &a, &None), Err(Ok(Error::InvalidDelegate)));
+
+    client.vote(&task_id, &x, &false);
+    assert_eq!(client.get_escrow(&task_id).real_votes, 3);
+
+    // A direct vote overrides the delegate
+    client.vote(&task_id, &a, &true);
+    let escrow = client.get_escrow(&task_id);
+    assert_eq!((escrow.real_votes, escrow.scam_votes), (2, 1));
+
+    client.vote(&task_id, &y, &false);
+    assert_eq!(client.get_escrow(&task_id).real_votes, 4);
+
+    // Re-delegating does not let B be counted twice
+    client.delegate(&b, &z, &None);
+    client.accept_delegation(&z, &b, &None);
+    assert_eq!(client.get_delegators(&x, &None).len(), 2);
+    client.vote(&task_id, &z, &false);
+    assert_eq!(client.get_escrow(&task_id).real_votes, 5);
+
+    client.revoke_delegation(&c, &Some(ngo.clone()));
+    assert_eq!(client.get_delegate(&c, &Some(ngo)), None);
+    assert_eq!(client.try_revoke_delegation(&c, &Some(Address::generate(&env))), Err(Ok(Error::NotDelegated)));
+}
+
+#[test]
+fn test_delegation_is_accepted_capped_and_bonded() {
+    let (env, _admin, donor, ngo, contract_id, client) = setup_contract();
+    let token = setup_token(&env, &client, &donor);
+    let reiatsu = setup_rewards(&env, &client, &contract_id, RewardSource::Mint);
+
+    let task_id = String::from_str(&env, "task_056");
+    client.create_escrow(&donor, &ngo, &token, &task_id, &funding(1000, 500, 9999999), &None);
+    client.submit_proof(&task_id, &String::from_str(&env, "QmProof"));
+    client.set_vote_bond(&task_id, &100, &50);
+
+    let (x, a, b) = (Address::generate(&env), Address::generate(&env), Address::generate(&env));
+    reiatsu.mint(&contract_id, &x, &1_000);
+
+    // An offer counts for nothing until the delegate accepts it
+    client.delegate(&a, &x, &None);
+    client.delegate(&b, &x, &None);
+    assert_eq!(client.get_pending_delegate(&a, &None), Some(x.clone()));
+    assert_eq!(client.get_delegate(&a, &None), None);
+    client.refuse_delegation(&x, &b, &None);
+    assert_eq!(client.get_pending_delegate(&b, &None), None);
+    assert_eq!(client.try_accept_delegation(&x, &b, &None), Err(Ok(Error::NotDelegated)));
+    client.accept_delegation(&x, &a, &None);
+
+    // X bonds for its own vote and for A's
+    client.vote(&task_id, &x, &false);
+    assert_eq!(client.get_escrow(&task_id).real_votes, 2);
+    assert_eq!(reiatsu.balance(&x), 800);
+    assert_eq!(client.get_votes(&task_id).get(0).unwrap().bonded, 200);
+
+    // A voting directly takes its weight back, and X its bond for it
+    reiatsu.mint(&contract_id, &a, &100);
+    client.vote(&task_id, &a, &true);
+    assert_eq!(reiatsu.balance(&x), 900);
+    assert_eq!(client.get_votes(&task_id).get(0).unwrap().bonded, 100);
+
+    // A delegate holds at most MAX_DELEGATORS per scope
+    for _ in 1..MAX_DELEGATORS {
+        let d = Address::generate(&env);
+        client.delegate(&d, &x, &None);
+        client.accept_delegation(&x, &d, &None);
+    }
+    let extra = Address::generate(&env);
+    client.delegate(&extra, &x, &None);
+    assert_eq!(client.try_accept_delegation(&x, &extra, &None), Err(Ok(Error::TooManyDelegators)));
+}
+
+#[test]
+fn test_release_fee_goes_to_treasury() {
+    let (env, _admin, donor, ngo, contract_id, client) = setup_contract();
+    let token = setup_token(&env, &client, &donor);
+    let token_client = TokenClient::new(&env, &token);
+
+    let treasury_id = env.register(DivisionTreasury, ());
+    let treasury = DivisionTreasuryClient::new(&env, &treasury_id);
+    treasury.initialize(&Address::generate(&env), &1_000_000, &1);
+    client.set_fee_config(&treasury_id, &200, &false, &FeeRoute::Deposit);
+    assert_eq!(
+        client.try_set_fee_config(&treasury_id, &5_000, &false, &FeeRoute::Transfer),
+        Err(Ok(Error::InvalidConfig))
+    );
+
+    let task_id = String::from_str(&env, "task_043");
+    client.create_escrow(&donor, &ngo, &token, &task_id, &funding(1000, 500, 9999999), &None);
+    client.submit_proof(&task_id, &String::from_str(&env, "QmProof"));
+    for _ in 0..3 {
+        client.vote(&task_id, &Address::generate(&env), &false);
+    }
+    client.release(&task_id);
+
+    // 2% of 500 to the treasury, recorded under the escrow contract
+    assert_eq!(token_client.balance(&ngo), 490);
+    assert_eq!(token_client.balance(&treasury_id), 10);
+    assert_eq!(treasury.get_balance(&contract_id), 10);
+    assert_eq!(client.get_platform_stats(), (0, 490, 0, 10));
+
+    // Refunds are fee-free unless configured
+    let refunded = String::from_str(&env, "task_044");
+    disputed_escrow(&env, &client, &donor, &ngo, &token, &refunded);
+    env.ledger().with_mut(|li| li.timestamp += DEFAULT_LOCK_DURATION + 1);
+    client.refund(&refunded);
+    assert_eq!(client.get_platform_stats(), (0, 490, 500, 10));
+}
+
+#[test]
+fn test_accuracy_weighted_votes_decide_release() {
+    let (env, _admin, donor, ngo, _contract_id, client) = setup_contract();
+    let token = setup_token(&env, &client, &donor);
+
+    // Two voters build a perfect record over five released escrows
+    let veteran_a = Address::generate(&env);
+    let veteran_b = Address::generate(&env);
+    for id in ["task_045", "task_046", "task_047", "task_048", "task_049"] {
+        let task_id = String::from_str(&env, id);
+        client.create_escrow(&donor, &ngo, &token, &task_id, &funding(200, 100, 9999999), &None);
+        client.submit_proof(&task_id, &String::from_str(&env, "QmProof"));
+        client.vote(&task_id, &veteran_a, &false);
+        client.vote(&task_id, &veteran_b, &false);
+        client.vote(&task_id, &Address::generate(&env), &false);
+        client.release(&task_id);
+    }
+
+    assert_eq!(
+        client.try_set_vote_weighting(&VoteWeighting::Badges),
+        Err(Ok(Error::InvalidConfig))
+    );
+    client.set_vote_weighting(&VoteWeighting::Accuracy);
+    let newcomer_a = Address::generate(&env);
+    let newcomer_b = Address::generate(&env);
+    assert_eq!(client.get_vote_weight(&veteran_a), 100);
+    assert_eq!(client.get_vote_weight(&newcomer_a), 50);
+
+    // A 2–2 headcount split, but the veterans outweigh the newcomers 200 to 100
+    let task_id = String::from_str(&env, "task_050");
+    client.create_escrow(&donor, &ngo, &token, &task_id, &funding(1000, 500, 9999999), &None);
+    client.submit_proof(&task_id, &String::from_str(&env, "QmProof"));
+    client.vote(&task_id, &veteran_a, &false);
+    client.vote(&task_id, &veteran_b, &false);
+    client.vote(&task_id, &newcomer_a, &true);
+    client.vote(&task_id, &newcomer_b, &true);
+
+    let escrow = client.get_escrow(&task_id);
+    assert_eq!((escrow.real_votes, escrow.scam_votes), (2, 2));
+    assert_eq!((escrow.real_weight, escrow.scam_weight), (200, 100));
+    assert_eq!(client.get_votes(&task_id).get(2).unwrap().weight, 50);
+    assert_eq!(client.try_dispute(&task_id), Err(Ok(Error::NotEnoughScamVotes)));
+
+    client.release(&task_id);
+    assert_eq!(client.get_escrow(&task_id).status, EscrowStatus::Released);
+}
+
+#[test]
+fn test_voting_window_opens_with_proof() {
+    let (env, _admin, donor, ngo, _cid, client) = setup_contract();
+    let token = setup_token(&env, &client, &donor);
+    client.set_vote_window(&3600);
+
+    let task_id = String::from_str(&env, "task_051");
+    client.create_escrow(&donor, &ngo, &token, &task_id, &funding(1000, 500, 9999999), &None);
+    assert_eq!(client.get_voting_phase(&task_id), VotingPhase::AwaitingProof);
+    assert_eq!(client.try_vote(&task_id, &Address::generate(&env), &false), Err(Ok(Error::ProofRequired)));
+
+    client.submit_proof(&task_id, &String::from_str(&env, "QmProof"));
+    let start = env.ledger().timestamp();
+    assert_eq!(client.get_voting_phase(&task_id), VotingPhase::Open);
+    assert_eq!(
+        client.get_voting_windows(&task_id),
+        VotingWindows { vote_start: start, vote_end: start + 3600, commit_end: 0, reveal_end: 0 }
+    );
+
+    for _ in 0..3 {
+        client.vote(&task_id, &Address::generate(&env), &false);
+    }
+    // Quorum is met, but the tally is not final until the window closes
+    assert_eq!(client.try_release(&task_id), Err(Ok(Error::WrongVotingPhase)));
+
+    env.ledger().with_mut(|li| li.timestamp = start + 3600);
+    assert_eq!(client.get_voting_phase(&task_id), VotingPhase::Final);
+    assert_eq!(client.try_vote(&task_id, &Address::generate(&env), &true), Err(Ok(Error::WrongVotingPhase)));
+
+    client.release(&task_id);
+    assert_eq!(client.get_voting_phase(&task_id), VotingPhase::Closed);
+}
+
+#[test]
+fn test_donor_receipts_record_outcome() {
+    let (env, _admin, donor, ngo, _cid, client) = setup_contract();
+    let token = setup_token(&env, &client, &donor);
+
+    let task_id = String::from_str(&env, "task_052");
+    client.create_escrow(&donor, &ngo, &token, &task_id, &funding(1000, 500, 9999999), &None);
+
+    let receipts = client.get_donor_receipts(&donor, &0, &10);
+    assert_eq!(receipts.len(), 1);
+    let donation = receipts.get(0).unwrap();
+    assert_eq!(donation.kind, ReceiptKind::Donation);
+    assert_eq!(donation.outcome, EscrowStatus::Locked);
+    assert_eq!((donation.ngo.clone(), donation.amount), (ngo.clone(), 500));
+    assert_eq!(client.get_receipt(&donation.id), Some(donation));
+    assert!(client.verify_receipt(&donor, &task_id));
+    assert!(!client.verify_receipt(&ngo, &task_id));
+
+    let refunded = String::from_str(&env, "task_053");
+    disputed_escrow(&env, &client, &donor, &ngo, &token, &refunded);
+    env.ledger().with_mut(|li| li.timestamp += DEFAULT_LOCK_DURATION + 1);
+    client.refund(&refunded);
+
+    assert_eq!(client.get_donor_receipt_count(&donor), 3);
+    assert_eq!(client.get_donor_receipts(&donor, &0, &2).len(), 2);
+    assert_eq!(client.get_donor_receipts(&donor, &3, &10).len(), 0);
+    let receipts = client.get_donor_receipts(&donor, &2, &10);
+    assert_eq!(receipts.len(), 1);
+    let settlement = receipts.get(0).unwrap();
+    assert_eq!(settlement.kind, ReceiptKind::Settlement);
+    assert_eq!(settlement.task_id, refunded);
+    assert_eq!(settlement.outcome, EscrowStatus::Refunded);
+    assert_eq!((settlement.amount, settlement.refunded), (500, 500));
+    assert!(settlement.issued_at > settlement.donated_at);
+}
+
+#[test]
+fn test_final_tally_below_quorum_can_be_refunded() {
+    let (env, _admin, donor, ngo, _cid, client) = setup_contract();
+    let token = setup_token(&env, &client, &donor);
+    let token_client = TokenClient::new(&env, &token);
+    client.set_vote_window(&3600);
+
+    let task_id = String::from_str(&env, "task_064");
+    client.create_escrow(&donor, &ngo, &token, &task_id, &funding(1000, 500, 9999999), &None);
+    client.submit_proof(&task_id, &String::from_str(&env, "QmProof"));
+    let start = env.ledger().timestamp();
+    client.vote(&task_id, &Address::generate(&env), &false);
+    client.vote(&task_id, &Address::generate(&env), &false);
+    assert_eq!(client.try_settle_expired(&task_id), Err(Ok(Error::WrongVotingPhase)));
+
+    // The window closes one vote short: nothing can release or dispute it
+    env.ledger().with_mut(|li| li.timestamp = start + 3600);
+    assert_eq!(client.try_release(&task_id), Err(Ok(Error::NotEnoughVotes)));
+    assert_eq!(client.try_vote(&task_id, &Address::generate(&env), &false), Err(Ok(Error::WrongVotingPhase)));
+    client.settle_expired(&task_id);
+    client.refund(&task_id);
+    assert_eq!(client.get_escrow(&task_id).status, EscrowStatus::Refunded);
+    assert_eq!(token_client.balance(&donor), 1_000_000);
+
+    // A decided tally still has to be settled by its vote
+    let decided = String::from_str(&env, "task_065");
+    client.create_escrow(&donor, &ngo, &token, &decided, &funding(1000, 500, 9999999), &None);
+    client.submit_proof(&decided, &String::from_str(&env, "QmProof"));
+    let start = env.ledger().timestamp();
+    for _ in 0..3 {
+        client.vote(&decided, &Address::generate(&env), &false);
+    }
+    env.ledger().with_mut(|li| li.timestamp = start + 3600);
+    assert_eq!(client.try_settle_expired(&decided), Err(Ok(Error::TallyDecided)));
+
+    // An unrevealed commit-reveal round is just as stuck once reveals close
+    let sealed = String::from_str(&env, "task_066");
+    client.create_escrow(&donor, &ngo, &token, &sealed, &funding(1000, 500, 9999999), &None);
+    client.submit_proof(&sealed, &String::from_str(&env, "QmProof"));
+    client.enable_commit_reveal(&sealed, &600, &600);
+    let voter = Address::generate(&env);
+    let salt = BytesN::from_array(&env, &[9u8; 32]);
+    client.commit_vote(&sealed, &voter, &client.compute_commitment(&voter, &sealed, &1, &false, &salt));
+    env.ledger().with_mut(|li| li.timestamp += 1200);
+    client.settle_expired(&sealed);
+    client.refund(&sealed);
+    assert_eq!(client.get_escrow(&sealed).status, EscrowStatus::Refunded);
+}
+
+#[test]
+fn test_milestone_round_below_quorum_can_be_refunded() {
+    let (env, _admin, donor, ngo, _cid, client) = setup_contract();
+    let token = setup_token(&env, &client, &donor);
+    client.set_vote_window(&3600);
+
+    let task_id = String::from_str(&env, "task_067");
+    client.create_milestone_escrow(&donor, &ngo, &token, &task_id, &three_milestones(&env), &None);
+    client.submit_milestone_proof(&task_id, &0, &String::from_str(&env, "QmM0"));
+    client.vote_milestone(&task_id, &0, &Address::generate(&env), &false);
+
+    env.ledger().with_mut(|li| li.timestamp += 3600);
+    assert_eq!(client.try_release_milestone(&task_id, &0), Err(Ok(Error::NotEnoughVotes)));
+    client.settle_expired(&task_id);
+    client.refund(&task_id);
+
+    let escrow = client.get_escrow(&task_id);
+    assert_eq!(escrow.status, EscrowStatus::Refunded);
+    assert_eq!(escrow.milestones.get(0).unwrap().status, MilestoneStatus::Refunded);
+    assert_eq!(TokenClient::new(&env, &token).balance(&donor), 1_000_000);
+}
+
+#[test]
+fn test_milestone_proof_is_fixed_once_voted_on() {
+    let (env, _admin, donor, ngo, _cid, client) = setup_contract();
+    let token = setup_token(&env, &client, &donor);
+
+    let task_id = String::from_str(&env, "task_068");
+    client.create_milestone_escrow(&donor, &ngo, &token, &task_id, &three_milestones(&env), &None);
+    client.submit_milestone_proof(&task_id, &0, &String::from_str(&env, "QmTypo"));
+    client.submit_milestone_proof(&task_id, &0, &String::from_str(&env, "QmM0"));
+
+    client.vote_milestone(&task_id, &0, &Address::generate(&env), &false);
+    assert_eq!(
+        client.try_submit_milestone_proof(&task_id, &0, &String::from_str(&env, "QmSwapped")),
+        Err(Ok(Error::VotingStarted))
+    );
+    assert_eq!(client.get_escrow(&task_id).milestones.get(0).unwrap().proof_cid, String::from_str(&env, "QmM0"));
+}
+
+#[test]
+fn test_suspended_delegator_stops_counting() {
+    let (env, _admin, donor, ngo, _cid, client) = setup_contract();
+    let token = setup_token(&env, &client, &donor);
+    let (gate_admin, registry, _badges) = setup_voter_gate(&env, &client, 0);
+
+    let task_id = String::from_str(&env, "task_069");
+    client.create_escrow(&donor, &ngo, &token, &task_id, &funding(1000, 500, 9999999), &None);
+    client.submit_proof(&task_id, &String::from_str(&env, "QmProof"));
+
+    let (x, a, b) = (Address::generate(&env), Address::generate(&env), Address::generate(&env));
+    for reaper in [&x, &a, &b] {
+        register_reaper(&env, &registry, reaper);
+    }
+    for delegator in [&a, &b] {
+        client.delegate(delegator, &x, &None);
+        client.accept_delegation(&x, delegator, &None);
+    }
+
+    // A was eligible when accepted, but is suspended before X votes
+    registry.suspend(&gate_admin, &a);
+    client.vote(&task_id, &x, &false);
+    assert_eq!(client.get_escrow(&task_id).real_votes, 2);
+    assert_eq!(client.get_votes(&task_id).get(0).unwrap().delegators, vec![&env, b.clone()]);
+}
@@ -1,6 +1,6 @@
 #![no_std]
 use soroban_sdk::{
-    contract, contractimpl, contracttype, symbol_short,
+    contract, contracterror, contractimpl, contracttype, symbol_short,
     Env, Address, String, Vec, vec, IntoVal,
 };
 
@@ -8,8 +8,50 @@ mod test;
 
 // ═══════════════════════════════════════════════════════════════════
 //  MISSION REGISTRY v2 — FULL LIFECYCLE, DEADLINES, MULTI-VALIDATOR
+//  Validators: Only admin-registered, active validators may seal, each
+//              optionally limited to certain divisions and danger bands,
+//              and never a mission they captain or are assigned to
+//  Team: Reapers apply to Active missions; the captain accepts the assigned
+//        team; on sealing every assignee gets a badge and the team splits
+//        the mission's Reiatsu reward
+//  Expiry: Anyone may sweep past-deadline missions to Expired, by ID batch
+//          or by walking the active-mission index a bounded step at a time
+//  Proofs: Every seal's proof CID and verdict note is kept per mission
+//  Review: Validators seal or reject a Review submission; rejection sends
+//          it back to InProgress, and the mission auto-fails after the
+//          configured number of rejections, each from a different validator
 // ═══════════════════════════════════════════════════════════════════
 
+const MAX_DIVISIONS: u32 = 13;
+const MAX_NOTE_LEN: u32 = 280;   // Bytes allowed in a validator's verdict note
+const DEFAULT_MAX_REJECTIONS: u32 = 3;
+const ACTIVE_BUCKET_SIZE: u32 = 50;  // Mission IDs per active-index storage entry
+const MAX_PAGE_SIZE: u32 = 50;       // Active-index positions read per page query
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    MissionNotFound = 1,
+    AlreadySealed = 2,
+    MissionClosed = 3,          // Failed or Expired
+    DeadlinePassed = 4,
+    NotValidator = 5,
+    ValidatorSuspended = 6,
+    CaptainCannotSeal = 7,
+    OutOfScope = 8,             // Validator not scoped to the mission's division/danger band
+    AlreadySealedByValidator = 9,
+    NotCaptain = 10,
+    NotOpenForTeam = 11,        // Applications/acceptance need an Active or InProgress mission
+    AlreadyApplied = 12,        // Already an applicant, an assignee or the captain
+    NotApplicant = 13,
+    NoAssignees = 14,
+    NoteTooLong = 15,
+    NotInReview = 16,
+    AssigneeCannotSeal = 17,    // Validators on the mission's team cannot judge it
+    AlreadyRejectedByValidator = 18,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum MissionStatus {
@@ -25,6 +67,7 @@ pub enum MissionStatus {
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Mission {
     pub title: String,
+    pub division: u32,          // Gotei division 1-13; 0 = unassigned
     pub danger_level: u32,
     pub status: MissionStatus,
     pub captain: Address,
@@ -32,6 +75,7 @@ pub struct Mission {
     pub deadline: u64,          // Timestamp; 0 = no deadline
     pub seal_count: u32,        // Number of validator seals
     pub required_seals: u32,    // 1 for Low/Med, 2+ for High/Extreme
+    pub revisions: u32,         // Times a Review submission was rejected
 }
 
 #[contracttype]
@@ -41,6 +85,42 @@ pub struct MissionProof {
     pub proof_cid: String,
     pub validator: Address,
     pub timestamp: u64,
+    pub note: String,           // Validator's short verdict (may be empty)
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Rejection {
+    pub validator: Address,
+    pub reason_cid: String,
+    pub revision: u32,          // The mission's revision count after this rejection
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DangerBand {
+    Low,        // danger 0-40
+    Medium,     // 41-70
+    High,       // 71-90
+    Extreme,    // 91+
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ValidatorStatus {
+    Active,
+    Suspended,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Validator {
+    pub address: Address,
+    pub status: ValidatorStatus,
+    pub divisions: Vec<u32>,            // Empty = any division
+    pub danger_bands: Vec<DangerBand>,  // Empty = any danger band
+    pub added_at: u64,
 }
 
 #[contracttype]
@@ -50,18 +130,30 @@ pub struct MissionCounter {
     pub active: u32,
     pub sealed: u32,
     pub failed: u32,
+    pub expired: u32,
 }
 
 #[contracttype]
 pub enum DataKey {
     Mission(String),
-    Proof(String),
+    Proofs(String),              // Vec<MissionProof>, one per seal in order
+    Rejections(String),          // Vec<Rejection>, one per rejected Review
     MissionValidators(String),   // Vec<Address> of validators who sealed
     Counter,
     CaptainMissions(Address),    // Vec<String> of mission IDs per captain
+    ActiveBucket(u32),           // Vec<String> of up to ACTIVE_BUCKET_SIZE missions not yet sealed/failed/expired
+    ActiveLen,                   // u32; entries across all ActiveBucket entries
+    ActivePos(String),           // u32; a mission's position in the active index
+    SweepCursor,                 // u32; active-index position the next sweep starts at
+    Applicants(String),          // Vec<Address> of reapers awaiting acceptance
+    Assignees(String),           // Vec<Address> of the accepted team
+    ReaperMissions(Address),     // Vec<String> of mission IDs a reaper is assigned to
     BadgeContract,
     TokenContract,
     Admin,
+    Validator(Address),          // Registered validator → Validator
+    ValidatorSet,                // Vec<Address> of registered validators
+    MaxRejections,               // u32; rejections before a mission auto-fails
 }
 
 #[contract]
@@ -79,7 +171,7 @@ impl MissionRegistry {
         }
         env.storage().instance().set(&DataKey::Admin, &admin);
         env.storage().persistent().set(&DataKey::Counter, &MissionCounter {
-            total: 0, active: 0, sealed: 0, failed: 0,
+            total: 0, active: 0, sealed: 0, failed: 0, expired: 0,
         });
     }
 
@@ -97,15 +189,113 @@ impl MissionRegistry {
         env.storage().instance().set(&DataKey::TokenContract, &token_contract);
     }
 
+    /// Set how many Review rejections a mission survives before it auto-fails.
+    pub fn set_max_rejections(env: Env, admin: Address, max_rejections: u32) {
+        admin.require_auth();
+        Self::require_admin(&env, &admin);
+        if max_rejections == 0 {
+            panic!("Max rejections must be at least 1");
+        }
+        env.storage().instance().set(&DataKey::MaxRejections, &max_rejections);
+    }
+
+    // ── VALIDATOR SET ────────────────────────────────────────────
+
+    /// Register a validator. Empty `divisions`/`danger_bands` mean no restriction.
+    pub fn add_validator(
+        env: Env, admin: Address, validator: Address,
+        divisions: Vec<u32>, danger_bands: Vec<DangerBand>,
+    ) {
+        admin.require_auth();
+        Self::require_admin(&env, &admin);
+
+        if env.storage().persistent().has(&DataKey::Validator(validator.clone())) {
+            panic!("Validator already registered");
+        }
+        for i in 0..divisions.len() {
+            let division = divisions.get(i).unwrap();
+            if division == 0 || division > MAX_DIVISIONS { panic!("Division must be 1-13"); }
+        }
+
+        let record = Validator {
+            address: validator.clone(),
+            status: ValidatorStatus::Active,
+            divisions,
+            danger_bands,
+            added_at: env.ledger().timestamp(),
+        };
+        env.storage().persistent().set(&DataKey::Validator(validator.clone()), &record);
+
+        let mut set: Vec<Address> = env.storage().persistent()
+            .get(&DataKey::ValidatorSet).unwrap_or(vec![&env]);
+        set.push_back(validator);
+        env.storage().persistent().set(&DataKey::ValidatorSet, &set);
+
+        env.events().publish(
+            (symbol_short!("mission"), symbol_short!("val_add")),
+            record,
+        );
+    }
+
+    /// Remove a validator from the set. Seals already given stay on record.
+    pub fn remove_validator(env: Env, admin: Address, validator: Address) {
+        admin.require_auth();
+        Self::require_admin(&env, &admin);
+
+        if !env.storage().persistent().has(&DataKey::Validator(validator.clone())) {
+            panic!("Validator not found");
+        }
+        env.storage().persistent().remove(&DataKey::Validator(validator.clone()));
+
+        let mut set: Vec<Address> = env.storage().persistent()
+            .get(&DataKey::ValidatorSet).unwrap_or(vec![&env]);
+        if let Some(pos) = set.first_index_of(&validator) {
+            set.remove(pos);
+        }
+        env.storage().persistent().set(&DataKey::ValidatorSet, &set);
+
+        env.events().publish(
+            (symbol_short!("mission"), symbol_short!("val_rm")),
+            (validator, env.ledger().timestamp()),
+        );
+    }
+
+    pub fn suspend_validator(env: Env, admin: Address, validator: Address) {
+        admin.require_auth();
+        Self::require_admin(&env, &admin);
+        Self::set_validator_status(&env, &validator, ValidatorStatus::Suspended);
+
+        env.events().publish(
+            (symbol_short!("mission"), symbol_short!("val_susp")),
+            (validator, env.ledger().timestamp()),
+        );
+    }
+
+    pub fn reinstate_validator(env: Env, admin: Address, validator: Address) {
+        admin.require_auth();
+        Self::require_admin(&env, &admin);
+        Self::set_validator_status(&env, &validator, ValidatorStatus::Active);
+
+        env.events().publish(
+            (symbol_short!("mission"), symbol_short!("val_rein")),
+            (validator, env.ledger().timestamp()),
+        );
+    }
+
     // ── MISSION LIFECYCLE ────────────────────────────────────────
 
-    /// Register a new mission. Danger level determines seal requirements.
+    /// Register a new mission. Danger level determines seal requirements;
+    /// division (0 = unassigned) and danger level decide which validators may seal it.
     pub fn register_mission(
         env: Env, captain: Address, mission_id: String,
-        title: String, danger_level: u32, deadline: u64,
+        title: String, danger_level: u32, deadline: u64, division: u32,
     ) {
         captain.require_auth();
 
+        if division > MAX_DIVISIONS {
+            panic!("Division must be 0-13");
+        }
+
         if env.storage().persistent().has(&DataKey::Mission(mission_id.clone())) {
             panic!("Mission ID already exists");
         }
@@ -115,6 +305,7 @@ impl MissionRegistry {
 
         let mission = Mission {
             title,
+            division,
             danger_level,
             status: MissionStatus::Active,
             captain: captain.clone(),
@@ -122,6 +313,7 @@ impl MissionRegistry {
             deadline,
             seal_count: 0,
             required_seals,
+            revisions: 0,
         };
 
         env.storage().persistent().set(&DataKey::Mission(mission_id.clone()), &mission);
@@ -136,6 +328,8 @@ impl MissionRegistry {
         captain_missions.push_back(mission_id.clone());
         env.storage().persistent().set(&DataKey::CaptainMissions(captain), &captain_missions);
 
+        Self::push_active(&env, &mission_id);
+
         // Update counter
         let mut counter: MissionCounter = Self::get_counter_internal(&env);
         counter.total += 1;
@@ -148,6 +342,72 @@ impl MissionRegistry {
         );
     }
 
+    // ── TEAM ─────────────────────────────────────────────────────
+
+    /// Apply to join an Active mission's team.
+    pub fn apply(env: Env, reaper: Address, mission_id: String) -> Result<(), Error> {
+        reaper.require_auth();
+
+        let mission: Mission = env.storage().persistent()
+            .get(&DataKey::Mission(mission_id.clone())).ok_or(Error::MissionNotFound)?;
+        if mission.status != MissionStatus::Active {
+            return Err(Error::NotOpenForTeam);
+        }
+        if reaper == mission.captain {
+            return Err(Error::AlreadyApplied);
+        }
+
+        let mut applicants = Self::get_applicants(env.clone(), mission_id.clone());
+        let assignees = Self::get_assignees(env.clone(), mission_id.clone());
+        if applicants.contains(&reaper) || assignees.contains(&reaper) {
+            return Err(Error::AlreadyApplied);
+        }
+        applicants.push_back(reaper.clone());
+        env.storage().persistent().set(&DataKey::Applicants(mission_id.clone()), &applicants);
+
+        env.events().publish(
+            (symbol_short!("mission"), symbol_short!("applied")),
+            (mission_id, reaper),
+        );
+
+        Ok(())
+    }
+
+    /// Captain accepts an applicant onto the assigned team. May be called
+    /// once per reaper, while the mission is Active or InProgress.
+    pub fn accept_applicant(env: Env, captain: Address, mission_id: String, reaper: Address) -> Result<(), Error> {
+        captain.require_auth();
+
+        let mission: Mission = env.storage().persistent()
+            .get(&DataKey::Mission(mission_id.clone())).ok_or(Error::MissionNotFound)?;
+        if mission.captain != captain {
+            return Err(Error::NotCaptain);
+        }
+        if mission.status != MissionStatus::Active && mission.status != MissionStatus::InProgress {
+            return Err(Error::NotOpenForTeam);
+        }
+
+        let mut applicants = Self::get_applicants(env.clone(), mission_id.clone());
+        let pos = applicants.first_index_of(&reaper).ok_or(Error::NotApplicant)?;
+        applicants.remove(pos);
+        env.storage().persistent().set(&DataKey::Applicants(mission_id.clone()), &applicants);
+
+        let mut assignees = Self::get_assignees(env.clone(), mission_id.clone());
+        assignees.push_back(reaper.clone());
+        env.storage().persistent().set(&DataKey::Assignees(mission_id.clone()), &assignees);
+
+        let mut missions = Self::get_missions_by_assignee(env.clone(), reaper.clone());
+        missions.push_back(mission_id.clone());
+        env.storage().persistent().set(&DataKey::ReaperMissions(reaper.clone()), &missions);
+
+        env.events().publish(
+            (symbol_short!("mission"), symbol_short!("assigned")),
+            (mission_id, reaper),
+        );
+
+        Ok(())
+    }
+
     /// Advance mission status: Active → InProgress → Review
     pub fn advance_status(env: Env, captain: Address, mission_id: String) {
         captain.require_auth();
@@ -174,43 +434,49 @@ impl MissionRegistry {
     }
 
     /// Seal a mission with proof. Multi-validator for high-danger missions.
+    /// The captain must first advance the mission to Review.
+    /// Only active registered validators scoped to the mission may seal,
+    /// and never the mission's own captain or assignees. Past-deadline missions are
+    /// rejected; `expire_missions`/`sweep_expired` move them to Expired.
+    /// The final seal mints a badge to every assignee and splits the Reiatsu
+    /// reward evenly across them (the first assignee takes any remainder).
+    /// Every seal's proof and verdict note is kept (see `get_proofs`).
     pub fn seal_proof(
-        env: Env, validator: Address, reaper: Address,
-        mission_id: String, proof_cid: String,
-    ) {
+        env: Env, validator: Address,
+        mission_id: String, proof_cid: String, note: String,
+    ) -> Result<(), Error> {
         validator.require_auth();
 
+        if note.len() > MAX_NOTE_LEN {
+            return Err(Error::NoteTooLong);
+        }
+
         let mut mission: Mission = env.storage().persistent()
-            .get(&DataKey::Mission(mission_id.clone())).expect("Mission not found");
+            .get(&DataKey::Mission(mission_id.clone())).ok_or(Error::MissionNotFound)?;
 
-        if mission.status == MissionStatus::Sealed {
-            panic!("Mission already sealed");
+        match mission.status {
+            MissionStatus::Review => {}
+            MissionStatus::Sealed => return Err(Error::AlreadySealed),
+            MissionStatus::Failed | MissionStatus::Expired => return Err(Error::MissionClosed),
+            _ => return Err(Error::NotInReview),
         }
-        if mission.status == MissionStatus::Failed || mission.status == MissionStatus::Expired {
-            panic!("Mission is failed/expired");
+        if Self::is_past_deadline(&env, &mission) {
+            return Err(Error::DeadlinePassed);
         }
 
-        // Check deadline
-        if mission.deadline > 0 && env.ledger().timestamp() > mission.deadline {
-            mission.status = MissionStatus::Expired;
-            env.storage().persistent().set(&DataKey::Mission(mission_id.clone()), &mission);
+        Self::check_validator(&env, &validator, &mission_id, &mission)?;
 
-            let mut counter = Self::get_counter_internal(&env);
-            if counter.active > 0 { counter.active -= 1; }
-            counter.failed += 1;
-            env.storage().persistent().set(&DataKey::Counter, &counter);
-
-            panic!("Mission deadline has passed");
+        let team = Self::get_assignees(env.clone(), mission_id.clone());
+        if team.is_empty() {
+            return Err(Error::NoAssignees);
         }
 
         // Check validator hasn't already sealed this mission
         let mut validators: Vec<Address> = env.storage().persistent()
             .get(&DataKey::MissionValidators(mission_id.clone()))
             .unwrap_or(vec![&env]);
-        for i in 0..validators.len() {
-            if validators.get(i).unwrap() == validator {
-                panic!("Validator already sealed this mission");
-            }
+        if validators.contains(&validator) {
+            return Err(Error::AlreadySealedByValidator);
         }
         validators.push_back(validator.clone());
         env.storage().persistent().set(
@@ -219,18 +485,21 @@ impl MissionRegistry {
 
         mission.seal_count += 1;
 
-        // Store proof
-        let proof = MissionProof {
+        // Append proof to the audit trail
+        let mut proofs = Self::get_proofs(env.clone(), mission_id.clone());
+        proofs.push_back(MissionProof {
             mission_id: mission_id.clone(),
             proof_cid,
             validator: validator.clone(),
             timestamp: env.ledger().timestamp(),
-        };
-        env.storage().persistent().set(&DataKey::Proof(mission_id.clone()), &proof);
+            note,
+        });
+        env.storage().persistent().set(&DataKey::Proofs(mission_id.clone()), &proofs);
 
         // Check if enough seals to finalize
         if mission.seal_count >= mission.required_seals {
             mission.status = MissionStatus::Sealed;
+            Self::remove_active(&env, &mission_id);
 
             let mut counter = Self::get_counter_internal(&env);
             if counter.active > 0 { counter.active -= 1; }
@@ -246,20 +515,28 @@ impl MissionRegistry {
                 } else {
                     String::from_str(&env, "Assistant Reaper")
                 };
-                env.invoke_contract::<()>(
-                    &badge_id, &symbol_short!("mint"),
-                    (reaper.clone(), mission_id.clone(), rank).into_val(&env),
-                );
+                for i in 0..team.len() {
+                    env.invoke_contract::<()>(
+                        &badge_id, &symbol_short!("mint"),
+                        (team.get(i).unwrap(), mission_id.clone(), rank.clone()).into_val(&env),
+                    );
+                }
             }
 
             // Inter-contract: Mint Reiatsu Token reward
             if let Some(token_id) = env.storage().instance().get::<_, Address>(&DataKey::TokenContract) {
                 let reward: i128 = 100 + (mission.danger_level as i128 * 10);
+                let share = reward / team.len() as i128;
+                let remainder = reward - share * team.len() as i128;
                 let minter = env.current_contract_address();
-                env.invoke_contract::<()>(
-                    &token_id, &symbol_short!("mint"),
-                    (minter, reaper.clone(), reward).into_val(&env),
-                );
+                for i in 0..team.len() {
+                    let amount = if i == 0 { share + remainder } else { share };
+                    if amount == 0 { continue; }
+                    env.invoke_contract::<()>(
+                        &token_id, &symbol_short!("mint"),
+                        (minter.clone(), team.get(i).unwrap(), amount).into_val(&env),
+                    );
+                }
             }
         }
 
@@ -269,32 +546,131 @@ impl MissionRegistry {
             (symbol_short!("mission"), symbol_short!("sealed")),
             (mission_id, env.ledger().timestamp()),
         );
+
+        Ok(())
+    }
+
+    /// Reject a Review submission. The mission returns to InProgress, its
+    /// partial seals are cleared and its revision count goes up; once the
+    /// count reaches `get_max_rejections` the mission fails instead. Each
+    /// validator may reject a mission only once, so failing it takes that many
+    /// distinct validators.
+    pub fn reject_review(
+        env: Env, validator: Address, mission_id: String, reason_cid: String,
+    ) -> Result<(), Error> {
+        validator.require_auth();
+
+        let mut mission: Mission = env.storage().persistent()
+            .get(&DataKey::Mission(mission_id.clone())).ok_or(Error::MissionNotFound)?;
+
+        match mission.status {
+            MissionStatus::Review => {}
+            MissionStatus::Sealed => return Err(Error::AlreadySealed),
+            MissionStatus::Failed | MissionStatus::Expired => return Err(Error::MissionClosed),
+            _ => return Err(Error::NotInReview),
+        }
+        if Self::is_past_deadline(&env, &mission) {
+            return Err(Error::DeadlinePassed);
+        }
+
+        Self::check_validator(&env, &validator, &mission_id, &mission)?;
+
+        let mut rejections = Self::get_rejections(env.clone(), mission_id.clone());
+        for i in 0..rejections.len() {
+            if rejections.get(i).unwrap().validator == validator {
+                return Err(Error::AlreadyRejectedByValidator);
+            }
+        }
+
+        mission.revisions += 1;
+        mission.seal_count = 0;
+        env.storage().persistent().set(
+            &DataKey::MissionValidators(mission_id.clone()), &Vec::<Address>::new(&env)
+        );
+
+        rejections.push_back(Rejection {
+            validator: validator.clone(),
+            reason_cid: reason_cid.clone(),
+            revision: mission.revisions,
+            timestamp: env.ledger().timestamp(),
+        });
+        env.storage().persistent().set(&DataKey::Rejections(mission_id.clone()), &rejections);
+
+        env.events().publish(
+            (symbol_short!("mission"), symbol_short!("rejected")),
+            (mission_id.clone(), validator, reason_cid, mission.revisions),
+        );
+
+        if mission.revisions >= Self::get_max_rejections(env.clone()) {
+            Self::mark_failed(&env, &mission_id, mission);
+        } else {
+            mission.status = MissionStatus::InProgress;
+            env.storage().persistent().set(&DataKey::Mission(mission_id), &mission);
+        }
+
+        Ok(())
     }
 
     /// Mark a mission as failed (admin or captain only).
     pub fn fail_mission(env: Env, caller: Address, mission_id: String) {
         caller.require_auth();
 
-        let mut mission: Mission = env.storage().persistent()
+        let mission: Mission = env.storage().persistent()
             .get(&DataKey::Mission(mission_id.clone())).expect("Mission not found");
 
         let is_admin = Self::is_admin(&env, &caller);
         if !is_admin && mission.captain != caller {
             panic!("Only admin or captain can fail a mission");
         }
+        if matches!(mission.status, MissionStatus::Sealed | MissionStatus::Failed | MissionStatus::Expired) {
+            panic!("Mission already closed");
+        }
 
-        mission.status = MissionStatus::Failed;
-        env.storage().persistent().set(&DataKey::Mission(mission_id.clone()), &mission);
+        Self::mark_failed(&env, &mission_id, mission);
+    }
 
-        let mut counter = Self::get_counter_internal(&env);
-        if counter.active > 0 { counter.active -= 1; }
-        counter.failed += 1;
-        env.storage().persistent().set(&DataKey::Counter, &counter);
+    // ── EXPIRY ───────────────────────────────────────────────────
+
+    /// Move the given missions to Expired if their deadline has passed.
+    /// Callable by anyone; IDs that are unknown, closed or not yet due are
+    /// skipped. Returns the number of missions expired.
+    pub fn expire_missions(env: Env, mission_ids: Vec<String>) -> u32 {
+        let mut expired = 0;
+        for i in 0..mission_ids.len() {
+            let id = mission_ids.get(i).unwrap();
+            if Self::try_expire(&env, &id) {
+                Self::remove_active(&env, &id);
+                expired += 1;
+            }
+        }
+        expired
+    }
 
-        env.events().publish(
-            (symbol_short!("mission"), symbol_short!("failed")),
-            (mission_id, env.ledger().timestamp()),
-        );
+    /// Check the next `limit` entries of the active-mission index, expiring those
+    /// past their deadline. A stored cursor carries on where the last sweep
+    /// stopped and wraps to the start at the end of the index. Callable by anyone.
+    /// Returns the number of missions expired.
+    pub fn sweep_expired(env: Env, limit: u32) -> u32 {
+        let mut pos: u32 = env.storage().persistent().get(&DataKey::SweepCursor).unwrap_or(0);
+        if pos >= Self::active_len(&env) { pos = 0; }
+
+        let mut checked = 0;
+        let mut expired = 0;
+        while checked < limit && pos < Self::active_len(&env) {
+            let id = Self::active_range(&env, pos, 1).get(0).unwrap();
+            checked += 1;
+            if Self::try_expire(&env, &id) {
+                // The last entry moves into `pos`, so check that slot again
+                Self::remove_active(&env, &id);
+                expired += 1;
+            } else {
+                pos += 1;
+            }
+        }
+
+        let next = if pos >= Self::active_len(&env) { 0 } else { pos };
+        env.storage().persistent().set(&DataKey::SweepCursor, &next);
+        expired
     }
 
     // ── QUERIES ──────────────────────────────────────────────────
@@ -303,8 +679,29 @@ impl MissionRegistry {
         env.storage().persistent().get(&DataKey::Mission(mission_id))
     }
 
+    /// The most recent seal's proof.
     pub fn get_proof(env: Env, mission_id: String) -> Option<MissionProof> {
-        env.storage().persistent().get(&DataKey::Proof(mission_id))
+        Self::get_proofs(env, mission_id).last()
+    }
+
+    /// Every seal's proof for a mission, oldest first.
+    pub fn get_proofs(env: Env, mission_id: String) -> Vec<MissionProof> {
+        env.storage().persistent()
+            .get(&DataKey::Proofs(mission_id))
+            .unwrap_or(vec![&env])
+    }
+
+    /// Every rejected Review for a mission, oldest first.
+    pub fn get_rejections(env: Env, mission_id: String) -> Vec<Rejection> {
+        env.storage().persistent()
+            .get(&DataKey::Rejections(mission_id))
+            .unwrap_or(vec![&env])
+    }
+
+    pub fn get_max_rejections(env: Env) -> u32 {
+        env.storage().instance()
+            .get(&DataKey::MaxRejections)
+            .unwrap_or(DEFAULT_MAX_REJECTIONS)
     }
 
     pub fn get_counter(env: Env) -> MissionCounter {
@@ -317,17 +714,216 @@ impl MissionRegistry {
             .unwrap_or(vec![&env])
     }
 
+    pub fn get_applicants(env: Env, mission_id: String) -> Vec<Address> {
+        env.storage().persistent()
+            .get(&DataKey::Applicants(mission_id))
+            .unwrap_or(vec![&env])
+    }
+
+    pub fn get_assignees(env: Env, mission_id: String) -> Vec<Address> {
+        env.storage().persistent()
+            .get(&DataKey::Assignees(mission_id))
+            .unwrap_or(vec![&env])
+    }
+
+    pub fn get_missions_by_assignee(env: Env, reaper: Address) -> Vec<String> {
+        env.storage().persistent()
+            .get(&DataKey::ReaperMissions(reaper))
+            .unwrap_or(vec![&env])
+    }
+
+    /// Number of missions that are not yet sealed, failed or expired.
+    pub fn get_active_count(env: Env) -> u32 {
+        Self::active_len(&env)
+    }
+
+    /// Up to `limit` active mission IDs (capped at MAX_PAGE_SIZE) starting at
+    /// index position `cursor`. Closing a mission moves the last entry into its
+    /// slot, so the order is not registration order.
+    pub fn get_active_missions(env: Env, cursor: u32, limit: u32) -> Vec<String> {
+        Self::active_range(&env, cursor, limit.min(MAX_PAGE_SIZE))
+    }
+
     pub fn get_validators(env: Env, mission_id: String) -> Vec<Address> {
         env.storage().persistent()
             .get(&DataKey::MissionValidators(mission_id))
             .unwrap_or(vec![&env])
     }
 
+    pub fn get_validator(env: Env, validator: Address) -> Option<Validator> {
+        env.storage().persistent().get(&DataKey::Validator(validator))
+    }
+
+    /// All registered validators, active and suspended.
+    pub fn get_validator_set(env: Env) -> Vec<Validator> {
+        let set: Vec<Address> = env.storage().persistent()
+            .get(&DataKey::ValidatorSet).unwrap_or(vec![&env]);
+        let mut result = Vec::new(&env);
+        for i in 0..set.len() {
+            if let Some(v) = env.storage().persistent().get::<_, Validator>(&DataKey::Validator(set.get(i).unwrap())) { result.push_back(v); }
+        }
+        result
+    }
+
     // ── INTERNALS ────────────────────────────────────────────────
 
     fn get_counter_internal(env: &Env) -> MissionCounter {
         env.storage().persistent().get(&DataKey::Counter)
-            .unwrap_or(MissionCounter { total: 0, active: 0, sealed: 0, failed: 0 })
+            .unwrap_or(MissionCounter { total: 0, active: 0, sealed: 0, failed: 0, expired: 0 })
+    }
+
+    fn band_of(danger_level: u32) -> DangerBand {
+        match danger_level {
+            0..=40 => DangerBand::Low,
+            41..=70 => DangerBand::Medium,
+            71..=90 => DangerBand::High,
+            _ => DangerBand::Extreme,
+        }
+    }
+
+    fn check_validator(env: &Env, validator: &Address, mission_id: &String, mission: &Mission) -> Result<(), Error> {
+        let record: Validator = env.storage().persistent()
+            .get(&DataKey::Validator(validator.clone())).ok_or(Error::NotValidator)?;
+        if record.status == ValidatorStatus::Suspended { return Err(Error::ValidatorSuspended); }
+        if *validator == mission.captain { return Err(Error::CaptainCannotSeal); }
+        if Self::get_assignees(env.clone(), mission_id.clone()).contains(validator) {
+            return Err(Error::AssigneeCannotSeal);
+        }
+
+        if !record.divisions.is_empty() && !record.divisions.contains(mission.division) {
+            return Err(Error::OutOfScope);
+        }
+        if !record.danger_bands.is_empty() && !record.danger_bands.contains(Self::band_of(mission.danger_level)) {
+            return Err(Error::OutOfScope);
+        }
+        Ok(())
+    }
+
+    fn is_past_deadline(env: &Env, mission: &Mission) -> bool {
+        mission.deadline > 0 && env.ledger().timestamp() > mission.deadline
+    }
+
+    fn mark_failed(env: &Env, mission_id: &String, mut mission: Mission) {
+        mission.status = MissionStatus::Failed;
+        Self::remove_active(env, mission_id);
+        env.storage().persistent().set(&DataKey::Mission(mission_id.clone()), &mission);
+
+        let mut counter = Self::get_counter_internal(env);
+        if counter.active > 0 { counter.active -= 1; }
+        counter.failed += 1;
+        env.storage().persistent().set(&DataKey::Counter, &counter);
+
+        env.events().publish(
+            (symbol_short!("mission"), symbol_short!("failed")),
+            (mission_id.clone(), env.ledger().timestamp()),
+        );
+    }
+
+    /// Expire one open, past-deadline mission. The caller removes it from the active index.
+    fn try_expire(env: &Env, mission_id: &String) -> bool {
+        let mut mission: Mission = match env.storage().persistent().get(&DataKey::Mission(mission_id.clone())) {
+            Some(m) => m,
+            None => return false,
+        };
+        if matches!(mission.status, MissionStatus::Sealed | MissionStatus::Failed | MissionStatus::Expired)
+            || !Self::is_past_deadline(env, &mission)
+        {
+            return false;
+        }
+
+        mission.status = MissionStatus::Expired;
+        env.storage().persistent().set(&DataKey::Mission(mission_id.clone()), &mission);
+
+        let mut counter = Self::get_counter_internal(env);
+        if counter.active > 0 { counter.active -= 1; }
+        counter.expired += 1;
+        env.storage().persistent().set(&DataKey::Counter, &counter);
+
+        env.events().publish(
+            (symbol_short!("mission"), symbol_short!("expired")),
+            (mission_id.clone(), env.ledger().timestamp()),
+        );
+        true
+    }
+
+    fn active_len(env: &Env) -> u32 {
+        env.storage().persistent().get(&DataKey::ActiveLen).unwrap_or(0)
+    }
+
+    /// Append a mission to the last active bucket, starting a new bucket every
+    /// ACTIVE_BUCKET_SIZE entries so no single entry grows without bound.
+    fn push_active(env: &Env, mission_id: &String) {
+        let len = Self::active_len(env);
+        let key = DataKey::ActiveBucket(len / ACTIVE_BUCKET_SIZE);
+        let mut bucket: Vec<String> = env.storage().persistent()
+            .get(&key).unwrap_or(vec![env]);
+        bucket.push_back(mission_id.clone());
+        env.storage().persistent().set(&key, &bucket);
+        env.storage().persistent().set(&DataKey::ActivePos(mission_id.clone()), &len);
+        env.storage().persistent().set(&DataKey::ActiveLen, &(len + 1));
+    }
+
+    /// Read active-index positions `start..start + count`, touching only the buckets involved.
+    fn active_range(env: &Env, start: u32, count: u32) -> Vec<String> {
+        let end = start.saturating_add(count).min(Self::active_len(env));
+        let mut ids: Vec<String> = vec![env];
+        let mut pos = start;
+        while pos < end {
+            let bucket: Vec<String> = env.storage().persistent()
+                .get(&DataKey::ActiveBucket(pos / ACTIVE_BUCKET_SIZE))
+                .unwrap_or(vec![env]);
+            let mut i = pos % ACTIVE_BUCKET_SIZE;
+            if i >= bucket.len() {
+                break;
+            }
+            while i < bucket.len() && pos < end {
+                ids.push_back(bucket.get(i).unwrap());
+                i += 1;
+                pos += 1;
+            }
+        }
+        ids
+    }
+
+    /// Drop a mission from the active index by moving the last entry into its
+    /// slot, so at most two buckets are read and written.
+    fn remove_active(env: &Env, mission_id: &String) {
+        let pos_key = DataKey::ActivePos(mission_id.clone());
+        let pos: u32 = match env.storage().persistent().get(&pos_key) {
+            Some(pos) => pos,
+            None => return,
+        };
+        let last = Self::active_len(env) - 1;
+        let last_key = DataKey::ActiveBucket(last / ACTIVE_BUCKET_SIZE);
+        let mut last_bucket: Vec<String> = env.storage().persistent().get(&last_key).unwrap();
+        let moved = last_bucket.pop_back().unwrap();
+
+        if pos != last {
+            if pos / ACTIVE_BUCKET_SIZE == last / ACTIVE_BUCKET_SIZE {
+                last_bucket.set(pos % ACTIVE_BUCKET_SIZE, moved.clone());
+            } else {
+                let key = DataKey::ActiveBucket(pos / ACTIVE_BUCKET_SIZE);
+                let mut bucket: Vec<String> = env.storage().persistent().get(&key).unwrap();
+                bucket.set(pos % ACTIVE_BUCKET_SIZE, moved.clone());
+                env.storage().persistent().set(&key, &bucket);
+            }
+            env.storage().persistent().set(&DataKey::ActivePos(moved), &pos);
+        }
+
+        if last_bucket.is_empty() {
+            env.storage().persistent().remove(&last_key);
+        } else {
+            env.storage().persistent().set(&last_key, &last_bucket);
+        }
+        env.storage().persistent().remove(&pos_key);
+        env.storage().persistent().set(&DataKey::ActiveLen, &last);
+    }
+
+    fn set_validator_status(env: &Env, validator: &Address, status: ValidatorStatus) {
+        let mut record: Validator = env.storage().persistent()
+            .get(&DataKey::Validator(validator.clone())).expect("Validator not found");
+        record.status = status;
+        env.storage().persistent().set(&DataKey::Validator(validator.clone()), &record);
     }
 
     fn require_admin(env: &Env, caller: &Address) {
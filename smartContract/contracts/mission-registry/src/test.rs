@@ -1,6 +1,8 @@
 #![cfg(test)]
 use super::*;
-use soroban_sdk::{testutils::Address as _, Address, Env, String as SorobanString};
+use soroban_sdk::{testutils::{Address as _, Ledger}, Address, Env, String as SorobanString, Vec};
+use reiatsu_token::{ReiatsuToken, ReiatsuTokenClient};
+use soul_badge::{SoulBadge, SoulBadgeClient};
 
 fn setup() -> (Env, MissionRegistryClient<'static>, Address) {
     let env = Env::default();
@@ -12,6 +14,20 @@ fn setup() -> (Env, MissionRegistryClient<'static>, Address) {
     (env, client, admin)
 }
 
+/// Have a fresh reaper apply to the mission and get accepted by the captain.
+fn assign_reaper(env: &Env, client: &MissionRegistryClient<'static>, captain: &Address, mission_id: &SorobanString) -> Address {
+    let reaper = Address::generate(env);
+    client.apply(&reaper, mission_id);
+    client.accept_applicant(captain, mission_id, &reaper);
+    reaper
+}
+
+/// Captain moves the mission Active → InProgress → Review, ready to seal.
+fn submit_for_review(client: &MissionRegistryClient<'static>, captain: &Address, mission_id: &SorobanString) {
+    client.advance_status(captain, mission_id);
+    client.advance_status(captain, mission_id);
+}
+
 #[test]
 fn test_register_mission() {
     let (env, client, _admin) = setup();
@@ -20,7 +36,7 @@ fn test_register_mission() {
         &captain,
         &SorobanString::from_str(&env, "M001"),
         &SorobanString::from_str(&env, "Rescue Op"),
-        &30, &0,
+        &30, &0, &0,
     );
     let mission = client.get_mission(&SorobanString::from_str(&env, "M001"));
     assert!(mission.is_some());
@@ -41,7 +57,7 @@ fn test_high_danger_requires_two_seals() {
         &captain,
         &SorobanString::from_str(&env, "EXTREME01"),
         &SorobanString::from_str(&env, "Extreme Danger"),
-        &90, &0,
+        &90, &0, &0,
     );
     let m = client.get_mission(&SorobanString::from_str(&env, "EXTREME01")).unwrap();
     assert_eq!(m.required_seals, 2);
@@ -55,7 +71,7 @@ fn test_advance_status() {
         &captain,
         &SorobanString::from_str(&env, "ADV01"),
         &SorobanString::from_str(&env, "Advance Test"),
-        &20, &0,
+        &20, &0, &0,
     );
     client.advance_status(&captain, &SorobanString::from_str(&env, "ADV01"));
     let m = client.get_mission(&SorobanString::from_str(&env, "ADV01")).unwrap();
@@ -68,22 +84,25 @@ fn test_advance_status() {
 
 #[test]
 fn test_seal_proof_low_danger() {
-    let (env, client, _admin) = setup();
+    let (env, client, admin) = setup();
     let captain = Address::generate(&env);
     let validator = Address::generate(&env);
-    let reaper = Address::generate(&env);
+    client.add_validator(&admin, &validator, &Vec::new(&env), &Vec::new(&env));
 
     client.register_mission(
         &captain,
         &SorobanString::from_str(&env, "SEAL01"),
         &SorobanString::from_str(&env, "Seal Test"),
-        &20, &0,
+        &20, &0, &0,
     );
+    assign_reaper(&env, &client, &captain, &SorobanString::from_str(&env, "SEAL01"));
+    submit_for_review(&client, &captain, &SorobanString::from_str(&env, "SEAL01"));
 
     client.seal_proof(
-        &validator, &reaper,
+        &validator,
         &SorobanString::from_str(&env, "SEAL01"),
         &SorobanString::from_str(&env, "QmProofCID"),
+        &SorobanString::from_str(&env, ""),
     );
 
     let m = client.get_mission(&SorobanString::from_str(&env, "SEAL01")).unwrap();
@@ -102,7 +121,7 @@ fn test_fail_mission() {
         &captain,
         &SorobanString::from_str(&env, "FAIL01"),
         &SorobanString::from_str(&env, "Fail Test"),
-        &30, &0,
+        &30, &0, &0,
     );
 
     client.fail_mission(&admin, &SorobanString::from_str(&env, "FAIL01"));
@@ -118,9 +137,418 @@ fn test_captain_missions_tracking() {
     let (env, client, _admin) = setup();
     let captain = Address::generate(&env);
 
-    client.register_mission(&captain, &SorobanString::from_str(&env, "CM1"), &SorobanString::from_str(&env, "A"), &10, &0);
-    client.register_mission(&captain, &SorobanString::from_str(&env, "CM2"), &SorobanString::from_str(&env, "B"), &20, &0);
+    client.register_mission(&captain, &SorobanString::from_str(&env, "CM1"), &SorobanString::from_str(&env, "A"), &10, &0, &0);
+    client.register_mission(&captain, &SorobanString::from_str(&env, "CM2"), &SorobanString::from_str(&env, "B"), &20, &0, &0);
 
     let missions = client.get_missions_by_captain(&captain);
     assert_eq!(missions.len(), 2);
 }
+
+#[test]
+fn test_unregistered_validator_cannot_seal() {
+    let (env, client, _admin) = setup();
+    let captain = Address::generate(&env);
+    client.register_mission(&captain, &SorobanString::from_str(&env, "VAL01"), &SorobanString::from_str(&env, "A"), &10, &0, &0);
+    submit_for_review(&client, &captain, &SorobanString::from_str(&env, "VAL01"));
+    let result = client.try_seal_proof(
+        &Address::generate(&env),
+        &SorobanString::from_str(&env, "VAL01"),
+        &SorobanString::from_str(&env, "QmProofCID"),
+        &SorobanString::from_str(&env, ""),
+    );
+    assert_eq!(result, Err(Ok(Error::NotValidator)));
+}
+
+#[test]
+fn test_scoped_validator_rejected_outside_division() {
+    let (env, client, admin) = setup();
+    let captain = Address::generate(&env);
+    let validator = Address::generate(&env);
+    client.add_validator(&admin, &validator, &vec![&env, 4u32], &Vec::new(&env));
+    client.register_mission(&captain, &SorobanString::from_str(&env, "VAL02"), &SorobanString::from_str(&env, "A"), &10, &0, &11);
+    submit_for_review(&client, &captain, &SorobanString::from_str(&env, "VAL02"));
+    let result = client.try_seal_proof(
+        &validator,
+        &SorobanString::from_str(&env, "VAL02"),
+        &SorobanString::from_str(&env, "QmProofCID"),
+        &SorobanString::from_str(&env, ""),
+    );
+    assert_eq!(result, Err(Ok(Error::OutOfScope)));
+}
+
+#[test]
+fn test_validator_set_management() {
+    let (env, client, admin) = setup();
+    let v1 = Address::generate(&env);
+    let v2 = Address::generate(&env);
+    client.add_validator(&admin, &v1, &vec![&env, 4u32], &vec![&env, DangerBand::High, DangerBand::Extreme]);
+    client.add_validator(&admin, &v2, &Vec::new(&env), &Vec::new(&env));
+    assert_eq!(client.get_validator_set().len(), 2);
+
+    client.suspend_validator(&admin, &v1);
+    assert_eq!(client.get_validator(&v1).unwrap().status, ValidatorStatus::Suspended);
+    let captain = Address::generate(&env);
+    let mission_id = SorobanString::from_str(&env, "VAL03");
+    client.register_mission(&captain, &mission_id, &SorobanString::from_str(&env, "A"), &95, &0, &4);
+    assign_reaper(&env, &client, &captain, &mission_id);
+    submit_for_review(&client, &captain, &mission_id);
+    assert_eq!(
+        client.try_seal_proof(&v1, &mission_id, &SorobanString::from_str(&env, "Qm"), &SorobanString::from_str(&env, "")),
+        Err(Ok(Error::ValidatorSuspended))
+    );
+
+    client.reinstate_validator(&admin, &v1);
+    client.seal_proof(&v1, &mission_id, &SorobanString::from_str(&env, "Qm"), &SorobanString::from_str(&env, ""));
+    assert_eq!(client.get_mission(&mission_id).unwrap().seal_count, 1);
+
+    client.remove_validator(&admin, &v2);
+    assert_eq!(client.get_validator_set().len(), 1);
+    assert!(client.get_validator(&v2).is_none());
+}
+
+#[test]
+fn test_seal_after_deadline_is_rejected_and_swept() {
+    let (env, client, admin) = setup();
+    let captain = Address::generate(&env);
+    let validator = Address::generate(&env);
+    client.add_validator(&admin, &validator, &Vec::new(&env), &Vec::new(&env));
+
+    let late = SorobanString::from_str(&env, "EXP01");
+    let open = SorobanString::from_str(&env, "EXP02");
+    client.register_mission(&captain, &late, &SorobanString::from_str(&env, "A"), &10, &100, &0);
+    client.register_mission(&captain, &open, &SorobanString::from_str(&env, "B"), &10, &0, &0);
+    assert_eq!(client.get_active_count(), 2);
+    assign_reaper(&env, &client, &captain, &late);
+    submit_for_review(&client, &captain, &late);
+
+    env.ledger().with_mut(|li| li.timestamp = 101);
+    let result = client.try_seal_proof(&validator, &late, &SorobanString::from_str(&env, "Qm"), &SorobanString::from_str(&env, ""));
+    assert_eq!(result, Err(Ok(Error::DeadlinePassed)));
+    assert_eq!(client.get_mission(&late).unwrap().status, MissionStatus::Review);
+
+    assert_eq!(client.sweep_expired(&10), 1);
+    assert_eq!(client.get_mission(&late).unwrap().status, MissionStatus::Expired);
+    assert_eq!(client.get_active_missions(&0, &10), vec![&env, open.clone()]);
+
+    let counter = client.get_counter();
+    assert_eq!((counter.active, counter.expired, counter.failed), (1, 1, 0));
+
+    // Already expired and no-deadline missions are skipped
+    assert_eq!(client.expire_missions(&vec![&env, late.clone(), open]), 0);
+    assert_eq!(
+        client.try_seal_proof(&validator, &late, &SorobanString::from_str(&env, "Qm"), &SorobanString::from_str(&env, "")),
+        Err(Ok(Error::MissionClosed))
+    );
+}
+
+#[test]
+fn test_sweep_walks_active_index_in_bounded_steps() {
+    let (env, client, _admin) = setup();
+    let captain = Address::generate(&env);
+
+    let open = SorobanString::from_str(&env, "SWP00");
+    client.register_mission(&captain, &open, &SorobanString::from_str(&env, "Open"), &10, &0, &0);
+    for id in ["SWP01", "SWP02", "SWP03"] {
+        client.register_mission(&captain, &SorobanString::from_str(&env, id), &SorobanString::from_str(&env, "Due"), &10, &100, &0);
+    }
+    env.ledger().with_mut(|li| li.timestamp = 101);
+
+    // Each sweep only looks at `limit` index entries
+    assert_eq!(client.sweep_expired(&2), 1);
+    assert_eq!(client.get_active_count(), 3);
+    assert_eq!(client.sweep_expired(&2), 2);
+    assert_eq!(client.get_active_missions(&0, &10), vec![&env, open.clone()]);
+
+    // The cursor wrapped; nothing left to expire
+    assert_eq!(client.sweep_expired(&2), 0);
+    assert_eq!(client.get_counter().expired, 3);
+}
+
+#[test]
+fn test_active_index_spans_buckets() {
+    let (env, client, admin) = setup();
+    let captain = Address::generate(&env);
+    for i in 0..55u8 {
+        let id = [b'B', b'K', b'T', b'0' + i / 10, b'0' + i % 10];
+        client.register_mission(&captain, &SorobanString::from_bytes(&env, &id), &SorobanString::from_str(&env, "M"), &10, &0, &0);
+    }
+    assert_eq!(client.get_active_count(), 55);
+
+    // Closing a mission in the first bucket moves the last entry into its slot
+    client.fail_mission(&admin, &SorobanString::from_str(&env, "BKT03"));
+    assert_eq!(client.get_active_count(), 54);
+    let first = client.get_active_missions(&0, &5);
+    assert_eq!(first.get(3).unwrap(), SorobanString::from_str(&env, "BKT54"));
+    assert_eq!(first.get(4).unwrap(), SorobanString::from_str(&env, "BKT04"));
+
+    // Pages are capped and stop at the end of the index
+    assert_eq!(client.get_active_missions(&0, &100).len(), 50);
+    let tail = client.get_active_missions(&50, &10);
+    assert_eq!(tail.len(), 4);
+    assert_eq!(tail.get(3).unwrap(), SorobanString::from_str(&env, "BKT53"));
+
+    // The moved entry can itself be removed from its new slot
+    client.fail_mission(&admin, &SorobanString::from_str(&env, "BKT54"));
+    assert_eq!(client.get_active_count(), 53);
+    assert_eq!(client.get_active_missions(&3, &1).get(0).unwrap(), SorobanString::from_str(&env, "BKT53"));
+}
+
+#[test]
+fn test_team_applications_and_assignment() {
+    let (env, client, admin) = setup();
+    let captain = Address::generate(&env);
+    let validator = Address::generate(&env);
+    client.add_validator(&admin, &validator, &Vec::new(&env), &Vec::new(&env));
+    let mission_id = SorobanString::from_str(&env, "TEAM01");
+    client.register_mission(&captain, &mission_id, &SorobanString::from_str(&env, "A"), &20, &0, &0);
+
+    // Sealing needs an assigned team
+    let solo = SorobanString::from_str(&env, "TEAM02");
+    client.register_mission(&captain, &solo, &SorobanString::from_str(&env, "B"), &20, &0, &0);
+    submit_for_review(&client, &captain, &solo);
+    assert_eq!(
+        client.try_seal_proof(&validator, &solo, &SorobanString::from_str(&env, "Qm"), &SorobanString::from_str(&env, "")),
+        Err(Ok(Error::NoAssignees))
+    );
+
+    let r1 = Address::generate(&env);
+    let r2 = Address::generate(&env);
+    client.apply(&r1, &mission_id);
+    client.apply(&r2, &mission_id);
+    assert_eq!(client.try_apply(&r1, &mission_id), Err(Ok(Error::AlreadyApplied)));
+    assert_eq!(client.try_apply(&captain, &mission_id), Err(Ok(Error::AlreadyApplied)));
+    assert_eq!(client.get_applicants(&mission_id), vec![&env, r1.clone(), r2.clone()]);
+
+    assert_eq!(client.try_accept_applicant(&r2, &mission_id, &r1), Err(Ok(Error::NotCaptain)));
+    client.accept_applicant(&captain, &mission_id, &r1);
+    assert_eq!(client.get_applicants(&mission_id), vec![&env, r2.clone()]);
+    assert_eq!(client.get_assignees(&mission_id), vec![&env, r1.clone()]);
+    assert_eq!(client.get_missions_by_assignee(&r1), vec![&env, mission_id.clone()]);
+    assert_eq!(client.try_accept_applicant(&captain, &mission_id, &r1), Err(Ok(Error::NotApplicant)));
+
+    // Applications close once work starts; accepting pending applicants does not
+    client.advance_status(&captain, &mission_id);
+    assert_eq!(client.try_apply(&Address::generate(&env), &mission_id), Err(Ok(Error::NotOpenForTeam)));
+    client.accept_applicant(&captain, &mission_id, &r2);
+
+    client.advance_status(&captain, &mission_id);
+    client.seal_proof(&validator, &mission_id, &SorobanString::from_str(&env, "Qm"), &SorobanString::from_str(&env, ""));
+    assert_eq!(client.get_assignees(&mission_id).len(), 2);
+    assert_eq!(client.get_mission(&mission_id).unwrap().status, MissionStatus::Sealed);
+}
+
+#[test]
+fn test_every_seal_proof_is_kept() {
+    let (env, client, admin) = setup();
+    let captain = Address::generate(&env);
+    let v1 = Address::generate(&env);
+    let v2 = Address::generate(&env);
+    client.add_validator(&admin, &v1, &Vec::new(&env), &Vec::new(&env));
+    client.add_validator(&admin, &v2, &Vec::new(&env), &Vec::new(&env));
+
+    let mission_id = SorobanString::from_str(&env, "AUDIT01");
+    client.register_mission(&captain, &mission_id, &SorobanString::from_str(&env, "Hollow Purge"), &90, &0, &0);
+    assign_reaper(&env, &client, &captain, &mission_id);
+    submit_for_review(&client, &captain, &mission_id);
+
+    let long_note = SorobanString::from_bytes(&env, &[b'x'; 281]);
+    assert_eq!(
+        client.try_seal_proof(&v1, &mission_id, &SorobanString::from_str(&env, "QmA"), &long_note),
+        Err(Ok(Error::NoteTooLong))
+    );
+
+    client.seal_proof(&v1, &mission_id, &SorobanString::from_str(&env, "QmA"), &SorobanString::from_str(&env, "Site cleared"));
+    client.seal_proof(&v2, &mission_id, &SorobanString::from_str(&env, "QmB"), &SorobanString::from_str(&env, "Confirmed"));
+
+    let proofs = client.get_proofs(&mission_id);
+    assert_eq!(proofs.len(), 2);
+    assert_eq!(proofs.get(0).unwrap().validator, v1);
+    assert_eq!(proofs.get(0).unwrap().note, SorobanString::from_str(&env, "Site cleared"));
+    assert_eq!(proofs.get(1).unwrap().proof_cid, SorobanString::from_str(&env, "QmB"));
+    assert_eq!(client.get_proof(&mission_id), proofs.get(1));
+    assert_eq!(client.get_mission(&mission_id).unwrap().status, MissionStatus::Sealed);
+}
+
+#[test]
+fn test_review_rejection_loop_auto_fails() {
+    let (env, client, admin) = setup();
+    let captain = Address::generate(&env);
+    let validator = Address::generate(&env);
+    let second = Address::generate(&env);
+    client.add_validator(&admin, &validator, &Vec::new(&env), &Vec::new(&env));
+    client.add_validator(&admin, &second, &Vec::new(&env), &Vec::new(&env));
+    assert_eq!(client.get_max_rejections(), 3);
+    client.set_max_rejections(&admin, &2);
+
+    let mission_id = SorobanString::from_str(&env, "REV01");
+    client.register_mission(&captain, &mission_id, &SorobanString::from_str(&env, "Menos Hunt"), &30, &0, &0);
+    assign_reaper(&env, &client, &captain, &mission_id);
+
+    let reason = SorobanString::from_str(&env, "QmReason");
+    assert_eq!(
+        client.try_reject_review(&validator, &mission_id, &reason),
+        Err(Ok(Error::NotInReview))
+    );
+
+    client.advance_status(&captain, &mission_id);
+    client.advance_status(&captain, &mission_id);
+    client.reject_review(&validator, &mission_id, &reason);
+
+    let m = client.get_mission(&mission_id).unwrap();
+    assert_eq!(m.status, MissionStatus::InProgress);
+    assert_eq!(m.revisions, 1);
+
+    client.advance_status(&captain, &mission_id);
+
+    // One validator alone cannot drive the mission to failure
+    assert_eq!(
+        client.try_reject_review(&validator, &mission_id, &SorobanString::from_str(&env, "QmReason2")),
+        Err(Ok(Error::AlreadyRejectedByValidator))
+    );
+    client.reject_review(&second, &mission_id, &SorobanString::from_str(&env, "QmReason2"));
+
+    let m = client.get_mission(&mission_id).unwrap();
+    assert_eq!(m.status, MissionStatus::Failed);
+    assert_eq!(m.revisions, 2);
+    assert_eq!(client.get_counter().failed, 1);
+    assert_eq!(client.get_active_count(), 0);
+
+    let rejections = client.get_rejections(&mission_id);
+    assert_eq!(rejections.len(), 2);
+    assert_eq!(rejections.get(0).unwrap().reason_cid, reason);
+    assert_eq!(rejections.get(1).unwrap().revision, 2);
+    assert_eq!(
+        client.try_reject_review(&validator, &mission_id, &reason),
+        Err(Ok(Error::MissionClosed))
+    );
+}
+
+#[test]
+fn test_rejection_clears_partial_seals() {
+    let (env, client, admin) = setup();
+    let captain = Address::generate(&env);
+    let v1 = Address::generate(&env);
+    let v2 = Address::generate(&env);
+    client.add_validator(&admin, &v1, &Vec::new(&env), &Vec::new(&env));
+    client.add_validator(&admin, &v2, &Vec::new(&env), &Vec::new(&env));
+
+    let mission_id = SorobanString::from_str(&env, "REV02");
+    client.register_mission(&captain, &mission_id, &SorobanString::from_str(&env, "Arrancar Raid"), &90, &0, &0);
+    assign_reaper(&env, &client, &captain, &mission_id);
+    client.advance_status(&captain, &mission_id);
+    client.advance_status(&captain, &mission_id);
+
+    let empty = SorobanString::from_str(&env, "");
+    client.seal_proof(&v1, &mission_id, &SorobanString::from_str(&env, "QmA"), &empty);
+    client.reject_review(&v2, &mission_id, &SorobanString::from_str(&env, "QmWhy"));
+
+    let m = client.get_mission(&mission_id).unwrap();
+    assert_eq!(m.status, MissionStatus::InProgress);
+    assert_eq!(m.seal_count, 0);
+    assert_eq!(client.get_validators(&mission_id).len(), 0);
+
+    // The revised submission needs both seals again
+    client.advance_status(&captain, &mission_id);
+    client.seal_proof(&v1, &mission_id, &SorobanString::from_str(&env, "QmA2"), &empty);
+    client.seal_proof(&v2, &mission_id, &SorobanString::from_str(&env, "QmB2"), &empty);
+    assert_eq!(client.get_mission(&mission_id).unwrap().status, MissionStatus::Sealed);
+    assert_eq!(client.get_proofs(&mission_id).len(), 3);
+}
+
+#[test]
+fn test_seal_requires_review() {
+    let (env, client, admin) = setup();
+    let captain = Address::generate(&env);
+    let validator = Address::generate(&env);
+    client.add_validator(&admin, &validator, &Vec::new(&env), &Vec::new(&env));
+
+    let mission_id = SorobanString::from_str(&env, "REV03");
+    client.register_mission(&captain, &mission_id, &SorobanString::from_str(&env, "Patrol"), &20, &0, &0);
+    assign_reaper(&env, &client, &captain, &mission_id);
+    let proof = SorobanString::from_str(&env, "QmEarly");
+    let empty = SorobanString::from_str(&env, "");
+
+    // Active and InProgress missions have nothing submitted to seal yet
+    assert_eq!(client.try_seal_proof(&validator, &mission_id, &proof, &empty), Err(Ok(Error::NotInReview)));
+    client.advance_status(&captain, &mission_id);
+    assert_eq!(client.try_seal_proof(&validator, &mission_id, &proof, &empty), Err(Ok(Error::NotInReview)));
+    assert_eq!(client.get_proofs(&mission_id).len(), 0);
+
+    client.advance_status(&captain, &mission_id);
+    client.seal_proof(&validator, &mission_id, &proof, &empty);
+    assert_eq!(client.get_mission(&mission_id).unwrap().status, MissionStatus::Sealed);
+}
+
+#[test]
+fn test_team_splits_the_reward_and_each_gets_a_badge() {
+    let (env, client, admin) = setup();
+    let captain = Address::generate(&env);
+    let validator = Address::generate(&env);
+    client.add_validator(&admin, &validator, &Vec::new(&env), &Vec::new(&env));
+
+    let badge_id = env.register(SoulBadge, ());
+    let badges = SoulBadgeClient::new(&env, &badge_id);
+    badges.initialize(&admin);
+    let token_id = env.register(ReiatsuToken, ());
+    let token = ReiatsuTokenClient::new(&env, &token_id);
+    token.initialize(&admin);
+    token.add_minter(&admin, &client.address);
+    client.set_badge_contract(&admin, &badge_id);
+    client.set_token_contract(&admin, &token_id);
+
+    // Danger 20 → 300 Reiatsu for the whole team, whatever its size
+    let mission_id = SorobanString::from_str(&env, "TEAM03");
+    client.register_mission(&captain, &mission_id, &SorobanString::from_str(&env, "Squad Sweep"), &20, &0, &0);
+    let team = [
+        assign_reaper(&env, &client, &captain, &mission_id),
+        assign_reaper(&env, &client, &captain, &mission_id),
+        assign_reaper(&env, &client, &captain, &mission_id),
+        assign_reaper(&env, &client, &captain, &mission_id),
+        assign_reaper(&env, &client, &captain, &mission_id),
+        assign_reaper(&env, &client, &captain, &mission_id),
+        assign_reaper(&env, &client, &captain, &mission_id),
+    ];
+    submit_for_review(&client, &captain, &mission_id);
+    client.seal_proof(&validator, &mission_id, &SorobanString::from_str(&env, "Qm"), &SorobanString::from_str(&env, ""));
+
+    // 300 / 7 = 42 each, the first assignee takes the 6 left over
+    assert_eq!(token.balance(&team[0]), 48);
+    for reaper in team.iter().skip(1) {
+        assert_eq!(token.balance(reaper), 42);
+    }
+    assert_eq!(team.iter().map(|r| token.balance(r)).sum::<i128>(), 300);
+    for reaper in team.iter() {
+        assert_eq!(badges.badge_count(reaper), 1);
+        assert!(badges.verify_badge(reaper, &mission_id));
+    }
+    assert_eq!(token.balance(&captain), 0);
+    assert_eq!(badges.badge_count(&captain), 0);
+}
+
+#[test]
+fn test_assigned_validator_cannot_judge_own_mission() {
+    let (env, client, admin) = setup();
+    let captain = Address::generate(&env);
+    let insider = Address::generate(&env);
+    let outsider = Address::generate(&env);
+    client.add_validator(&admin, &insider, &Vec::new(&env), &Vec::new(&env));
+    client.add_validator(&admin, &outsider, &Vec::new(&env), &Vec::new(&env));
+
+    let mission_id = SorobanString::from_str(&env, "TEAM04");
+    client.register_mission(&captain, &mission_id, &SorobanString::from_str(&env, "Inside Job"), &20, &0, &0);
+    client.apply(&insider, &mission_id);
+    client.accept_applicant(&captain, &mission_id, &insider);
+    submit_for_review(&client, &captain, &mission_id);
+
+    let empty = SorobanString::from_str(&env, "");
+    assert_eq!(
+        client.try_seal_proof(&insider, &mission_id, &SorobanString::from_str(&env, "Qm"), &empty),
+        Err(Ok(Error::AssigneeCannotSeal))
+    );
+    assert_eq!(
+        client.try_reject_review(&insider, &mission_id, &SorobanString::from_str(&env, "QmWhy")),
+        Err(Ok(Error::AssigneeCannotSeal))
+    );
+    client.seal_proof(&outsider, &mission_id, &SorobanString::from_str(&env, "Qm"), &empty);
+    assert_eq!(client.get_mission(&mission_id).unwrap().status, MissionStatus::Sealed);
+}
@@ -157,7 +157,7 @@ impl SoulBadge {
         // Decrement count
         let mut count: u32 = env.storage().persistent()
             .get(&DataKey::BadgeCount(reaper.clone())).unwrap_or(0);
-        if count > 0 { count -= 1; }
+        count = count.saturating_sub(1);
         env.storage().persistent().set(&DataKey::BadgeCount(reaper.clone()), &count);
 
         env.events().publish(
@@ -234,7 +234,7 @@ impl SoulBadge {
 
         // Trim to limit
         let mut result: Vec<LeaderboardEntry> = vec![&env];
-        let cap = if limit < len as u32 { limit } else { len as u32 };
+        let cap = if limit < len { limit } else { len };
         for i in 0..cap {
             result.push_back(entries.get(i).unwrap());
         }